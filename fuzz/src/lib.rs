@@ -0,0 +1,35 @@
+//! Fuzz targets for each day, run from this directory with e.g.
+//! `cargo +nightly fuzz run day_5`.
+
+use aoc2022::days::{init_days, Day, Parsing};
+use std::sync::OnceLock;
+
+/// The days set up for lenient and for strict parsing.
+static DAYS: OnceLock<[Vec<Day>; 2]> = OnceLock::new();
+
+/// Feeds `data` to a day's solvers in both parsing modes, and to its
+/// visualizer and linter. They may reject it, but any panic is a bug for the
+/// fuzzer to report.
+pub fn fuzz_day(advent_day: u8, data: &[u8]) {
+    let modes = DAYS.get_or_init(|| {
+        [Parsing::Lenient, Parsing::Strict].map(|parsing| {
+            let mut days = init_days();
+            days.iter_mut().for_each(|day| day.set_parsing(parsing));
+            days
+        })
+    });
+    let input = String::from_utf8_lossy(data);
+
+    for days in modes {
+        let day = days
+            .iter()
+            .find(|day| day.advent_day() == advent_day)
+            .expect("the day is registered");
+
+        for problem in day.parts() {
+            let _ = problem.solve_input(&input);
+        }
+        let _ = day.visualize_input(&input);
+        let _ = day.lint(&input);
+    }
+}
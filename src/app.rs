@@ -1,25 +1,105 @@
-use crate::days::{init_days, Day};
+use crate::clipboard;
+use crate::config::Config;
+use crate::days::{init_days, solve_parallel, Day, Status};
+use crate::editor::Editor;
+use crate::filter::Filter;
+use crate::keymap::{Action, Keymap};
+use crate::opener;
+use crate::sort::Sort;
+use crate::theme::{Symbols, Theme};
 
-use std::process::Command;
-use tui::widgets::TableState;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use itertools::Itertools;
+use std::{
+    cmp::Ordering,
+    fs,
+    time::{Duration, Instant},
+};
+use tui::{layout::Rect, widgets::TableState};
+
+const STATUS_TTL: Duration = Duration::from_secs(5);
+const DOUBLE_CLICK: Duration = Duration::from_millis(400);
 
 pub struct StatefulTable<T> {
     pub state: TableState,
     pub items: Vec<T>,
+    /// Indexes into `items` of the rows currently shown, in display order.
+    visible: Vec<usize>,
+    offset: usize,
 }
 
 impl<T> StatefulTable<T> {
     pub fn with_rows(items: Vec<T>) -> StatefulTable<T> {
         StatefulTable {
             state: TableState::default(),
+            visible: (0..items.len()).collect(),
             items,
+            offset: 0,
+        }
+    }
+
+    pub fn visible_items(&self) -> impl Iterator<Item = &T> {
+        self.visible.iter().map(move |&i| &self.items[i])
+    }
+
+    pub fn visible_len(&self) -> usize {
+        self.visible.len()
+    }
+
+    /// Shows only the items matching `predicate`, ordered by `compare`,
+    /// keeping the selected item selected when it is still shown.
+    pub fn arrange<F, C>(&mut self, predicate: F, compare: C)
+    where
+        F: Fn(&T) -> bool,
+        C: Fn(&T, &T) -> Ordering,
+    {
+        let selected = self.current_index();
+
+        self.visible = (0..self.items.len())
+            .filter(|&i| predicate(&self.items[i]))
+            .collect();
+        self.visible
+            .sort_by(|&a, &b| compare(&self.items[a], &self.items[b]));
+
+        let position = selected.and_then(|s| self.visible.iter().position(|&i| i == s));
+        match position {
+            Some(p) => self.state.select(Some(p)),
+            None if self.visible.is_empty() => self.state.select(None),
+            None if self.state.selected().is_some() => self.state.select(Some(0)),
+            None => {}
+        }
+    }
+
+    /// Tracks the scroll offset the same way `Table` does while rendering,
+    /// since `TableState` keeps its own offset private.
+    pub fn update_offset(&mut self, visible_rows: usize) {
+        let selected = self.state.selected().unwrap_or(0);
+
+        if selected < self.offset {
+            self.offset = selected;
+        } else if visible_rows > 0 && selected >= self.offset + visible_rows {
+            self.offset = selected + 1 - visible_rows;
+        }
+    }
+
+    /// Position of the item drawn on the given visible row.
+    pub fn item_at_row(&self, row: usize) -> Option<usize> {
+        let i = self.offset + row;
+        if i < self.visible.len() {
+            Some(i)
+        } else {
+            None
         }
     }
 
     pub fn next(&mut self) {
+        if self.visible.is_empty() {
+            return;
+        }
+
         let i = match self.state.selected() {
             Some(i) => {
-                if i >= self.items.len() - 1 {
+                if i >= self.visible.len() - 1 {
                     0
                 } else {
                     i + 1
@@ -31,10 +111,14 @@ impl<T> StatefulTable<T> {
     }
 
     pub fn previous(&mut self) {
+        if self.visible.is_empty() {
+            return;
+        }
+
         let i = match self.state.selected() {
             Some(i) => {
                 if i == 0 {
-                    self.items.len() - 1
+                    self.visible.len() - 1
                 } else {
                     i - 1
                 }
@@ -44,67 +128,740 @@ impl<T> StatefulTable<T> {
         self.state.select(Some(i));
     }
 
-    pub fn current_item(&self) -> &T {
+    /// Moves the selection by `delta` rows, stopping at either end instead of
+    /// wrapping around.
+    pub fn move_by(&mut self, delta: isize) {
+        if self.visible.is_empty() {
+            return;
+        }
+
+        let current = self.state.selected().unwrap_or(0) as isize;
+        let last = self.visible.len() as isize - 1;
+        self.state
+            .select(Some((current + delta).clamp(0, last) as usize));
+    }
+
+    pub fn first(&mut self) {
+        if !self.visible.is_empty() {
+            self.state.select(Some(0));
+        }
+    }
+
+    pub fn last(&mut self) {
+        if !self.visible.is_empty() {
+            self.state.select(Some(self.visible.len() - 1));
+        }
+    }
+
+    /// Index into `items` of the selected item.
+    pub fn current_index(&self) -> Option<usize> {
         let i = self.state.selected().unwrap_or(0);
-        &self.items[i]
+        self.visible.get(i).copied()
+    }
+
+    pub fn current_item(&self) -> Option<&T> {
+        self.current_index().map(|i| &self.items[i])
+    }
+}
+
+pub struct StatusMessage {
+    pub text: String,
+    created: Instant,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Screen {
+    Days,
+    Benchmarks,
+    Logs,
+    Visualizations,
+}
+
+impl Screen {
+    pub const ALL: [Screen; 4] = [
+        Screen::Days,
+        Screen::Benchmarks,
+        Screen::Logs,
+        Screen::Visualizations,
+    ];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Screen::Days => "Days",
+            Screen::Benchmarks => "Benchmarks",
+            Screen::Logs => "Logs",
+            Screen::Visualizations => "Visualizations",
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        Screen::ALL.iter().position(|s| s == self).unwrap_or(0)
+    }
+
+    fn offset(&self, delta: isize) -> Screen {
+        let len = Screen::ALL.len() as isize;
+        Screen::ALL[(self.index() as isize + delta).rem_euclid(len) as usize]
     }
 }
 
+pub struct Visualization {
+    pub advent_day: u8,
+    pub frames: Vec<String>,
+    pub frame: usize,
+}
+
 pub struct App<'a> {
     pub title: &'a str,
     pub should_quit: bool,
+    pub screen: Screen,
     pub day_table: StatefulTable<Day>,
-    pub enhanced_graphics: bool,
+    pub theme: Theme,
+    pub symbols: Symbols,
+    pub config: Config,
+    pub keymap: Keymap,
+    pub filter: Filter,
+    pub sort: Sort,
+    pub status: Option<StatusMessage>,
+    pub logs: Vec<String>,
+    /// First log line shown, or `None` to follow the newest lines.
+    log_scroll: Option<u16>,
+    /// Log lines that fit on the logs screen, updated when it is drawn.
+    log_height: u16,
+    pub visualization: Option<Visualization>,
+    pub show_help: bool,
+    /// First binding shown in the help popup.
+    pub help_scroll: u16,
+    /// Bindings that fit in the help popup, updated when it is drawn.
+    help_height: u16,
+    pub show_detail: bool,
+    /// Custom input being edited for the selected day, shown beside the table.
+    pub editor: Option<Editor>,
+    pub detail_scroll: u16,
+    /// Characters scrolled off the left of every answer in the day table.
+    pub answer_scroll: usize,
+    /// Where the day table's rows were last drawn, below its header.
+    pub table_rows: Rect,
+    pub detail_area: Option<Rect>,
+    started: Instant,
+    last_click: Option<(Instant, usize)>,
+}
+
+fn contains(area: Rect, column: u16, row: u16) -> bool {
+    column >= area.left() && column < area.right() && row >= area.top() && row < area.bottom()
 }
 
 impl<'a> App<'a> {
-    pub fn new(title: &'a str, enhanced_graphics: bool) -> App<'a> {
+    pub fn new(title: &'a str, enhanced_graphics: bool, config: Config) -> App<'a> {
+        let mut days = init_days();
+        for day in days.iter_mut() {
+            day.set_parsing(config.parsing());
+        }
+
         App {
             title,
             should_quit: false,
-            day_table: StatefulTable::with_rows(init_days()),
-            enhanced_graphics,
+            screen: Screen::Days,
+            day_table: StatefulTable::with_rows(days),
+            theme: Theme::new(config.theme),
+            symbols: Symbols::new(enhanced_graphics),
+            keymap: Keymap::new(&config.keys),
+            filter: Filter::new(Duration::from_millis(config.slow_threshold_ms)),
+            sort: Sort::default(),
+            config,
+            status: None,
+            logs: vec![],
+            log_scroll: None,
+            log_height: 0,
+            visualization: None,
+            show_help: false,
+            help_scroll: 0,
+            help_height: 0,
+            show_detail: false,
+            editor: None,
+            detail_scroll: 0,
+            answer_scroll: 0,
+            table_rows: Rect::default(),
+            detail_area: None,
+            started: Instant::now(),
+            last_click: None,
+        }
+    }
+
+    pub fn log(&mut self, text: String) {
+        let elapsed = self.started.elapsed();
+        self.logs.push(format!(
+            "[{:>4}.{:03}] {}",
+            elapsed.as_secs(),
+            elapsed.subsec_millis(),
+            text
+        ));
+    }
+
+    pub fn set_status(&mut self, text: String) {
+        self.log(text.clone());
+        self.status = Some(StatusMessage {
+            text,
+            created: Instant::now(),
+        });
+    }
+
+    /// Solves the given `(item index, part)` pairs, logging each result.
+    /// With `reset`, cached results are cleared first so the inputs are read
+    /// from disk again. Days are solved in parallel when more than one is
+    /// given.
+    fn solve(&mut self, targets: Vec<(usize, u8)>, reset: bool) {
+        if reset {
+            for (i, part) in &targets {
+                self.day_table.items[*i].part_mut(*part).reset();
+            }
+        }
+
+        let threads = self.config.threads();
+        let started = Instant::now();
+        let solved = solve_parallel(&mut self.day_table.items, &targets, threads);
+        let wall_time = started.elapsed();
+
+        let mut solve_time = Duration::ZERO;
+        for (i, part) in &solved {
+            let day = &self.day_table.items[*i];
+            let advent_day = day.advent_day();
+
+            let message = match day.part(*part).status() {
+                Status::Solved(solution) => {
+                    solve_time += solution.elapsed;
+                    format!(
+                        "Solved Day {} Part {} in {:?}: {}",
+                        advent_day, part, solution.elapsed, solution.answer
+                    )
+                }
+                Status::Failed(error) => {
+                    format!("Day {} Part {} failed: {}", advent_day, part, error)
+                }
+                Status::Unsolved => continue,
+            };
+            self.log(message);
+        }
+
+        let days = solved.iter().map(|(i, _)| i).dedup().count();
+        if days > 1 {
+            self.set_status(format!(
+                "Solved {} parts of {} days on {} threads in {:?} (summed solve time {:?})",
+                solved.len(),
+                days,
+                threads.min(days),
+                wall_time,
+                solve_time
+            ));
+        }
+    }
+
+    fn selected_part(&self, part: u8) -> Vec<(usize, u8)> {
+        self.day_table
+            .current_index()
+            .map_or_else(Vec::new, |i| vec![(i, part)])
+    }
+
+    fn selected_parts(&self) -> Vec<(usize, u8)> {
+        self.day_table
+            .current_index()
+            .map_or_else(Vec::new, |i| vec![(i, 1), (i, 2)])
+    }
+
+    fn all_parts(&self) -> Vec<(usize, u8)> {
+        (0..self.day_table.items.len())
+            .flat_map(|i| [(i, 1), (i, 2)])
+            .collect()
+    }
+
+    fn copy_answer(&mut self, part: u8) {
+        let day = match self.day_table.current_item() {
+            Some(day) => day,
+            None => return,
+        };
+
+        let status = match day.part(part).solution() {
+            Some(answer) => match clipboard::copy(&self.config.clipboard, &answer) {
+                Ok(()) => format!("Copied Day {} Part {}: {}", day.advent_day(), part, answer),
+                Err(e) => format!("Failed to copy to clipboard: {}", e),
+            },
+            None => format!(
+                "Day {} Part {} has no answer to copy",
+                day.advent_day(),
+                part
+            ),
+        };
+
+        self.set_status(status);
+    }
+
+    fn open(&mut self, target: String) {
+        let status = match opener::open(self.config.opener.as_deref(), &target) {
+            Ok(()) => format!("Opened {}", target),
+            Err(e) => format!("Failed to open {}: {}", target, e),
+        };
+
+        self.set_status(status);
+    }
+
+    fn show_screen(&mut self, screen: Screen) {
+        self.screen = screen;
+
+        if screen == Screen::Visualizations {
+            self.load_visualization();
+        }
+    }
+
+    /// Renders the frames for the selected day, unless they are already loaded.
+    fn load_visualization(&mut self) {
+        let day = match self.day_table.current_item() {
+            Some(day) => day,
+            None => return,
+        };
+
+        let loaded = self
+            .visualization
+            .as_ref()
+            .is_some_and(|v| v.advent_day == day.advent_day());
+
+        if !loaded {
+            self.visualization = day.visualize().map(|frames| Visualization {
+                advent_day: day.advent_day(),
+                frames,
+                frame: 0,
+            });
         }
     }
 
     pub fn on_up(&mut self) {
         self.day_table.previous();
+        self.detail_scroll = 0;
     }
 
     pub fn on_down(&mut self) {
         self.day_table.next();
+        self.detail_scroll = 0;
+    }
+
+    pub fn on_enter(&mut self) {
+        self.show_detail = !self.show_detail;
+        self.detail_scroll = 0;
+    }
+
+    fn on_click(&mut self, column: u16, row: u16) {
+        if self.screen != Screen::Days || !contains(self.table_rows, column, row) {
+            return;
+        }
+
+        let row = (row - self.table_rows.top()) as usize;
+        if let Some(i) = self.day_table.item_at_row(row) {
+            if self.day_table.state.selected() != Some(i) {
+                self.detail_scroll = 0;
+            }
+            self.day_table.state.select(Some(i));
+
+            let double_click = matches!(
+                self.last_click,
+                Some((at, last)) if last == i && at.elapsed() <= DOUBLE_CLICK
+            );
+
+            if double_click {
+                self.solve(self.selected_parts(), false);
+                self.last_click = None;
+            } else {
+                self.last_click = Some((Instant::now(), i));
+            }
+        }
+    }
+
+    fn in_detail(&self, column: u16, row: u16) -> bool {
+        self.detail_area
+            .is_some_and(|area| contains(area, column, row))
+    }
+
+    pub fn on_mouse(&mut self, event: MouseEvent) {
+        let in_detail = self.screen == Screen::Days && self.in_detail(event.column, event.row);
+
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => self.on_click(event.column, event.row),
+
+            MouseEventKind::ScrollDown if in_detail => {
+                self.detail_scroll = self.detail_scroll.saturating_add(1);
+            }
+
+            MouseEventKind::ScrollUp if in_detail => {
+                self.detail_scroll = self.detail_scroll.saturating_sub(1);
+            }
+
+            MouseEventKind::ScrollDown => self.on_action(Action::Down),
+
+            MouseEventKind::ScrollUp => self.on_action(Action::Up),
+
+            _ => {}
+        }
+
+        self.arrange_days();
+    }
+
+    fn page_size(&self) -> isize {
+        self.table_rows.height.max(1) as isize
+    }
+
+    /// First log line that keeps the newest lines at the bottom of the view.
+    fn newest_log_scroll(&self) -> u16 {
+        self.logs.len().saturating_sub(self.log_height as usize) as u16
+    }
+
+    /// First log line shown on the logs screen.
+    pub fn log_offset(&self) -> u16 {
+        let newest = self.newest_log_scroll();
+        self.log_scroll.map_or(newest, |scroll| scroll.min(newest))
+    }
+
+    /// Scrolls the log to `scroll`. Scrolling back to the end follows new
+    /// lines again.
+    fn scroll_logs(&mut self, scroll: u16) {
+        let newest = self.newest_log_scroll();
+        self.log_scroll = if scroll >= newest { None } else { Some(scroll) };
+    }
+
+    pub fn set_log_height(&mut self, height: u16) {
+        self.log_height = height;
+        self.scroll_logs(self.log_offset());
+    }
+
+    /// Keys for the logs screen, which scroll through the log.
+    fn on_logs_action(&mut self, action: Action) -> bool {
+        let current = self.log_offset();
+        let page = self.log_height.max(1);
+
+        let scroll = match action {
+            Action::Up => current.saturating_sub(1),
+            Action::Down => current.saturating_add(1),
+            Action::PageUp => current.saturating_sub(page),
+            Action::PageDown => current.saturating_add(page),
+            Action::Top => 0,
+            Action::Bottom => u16::MAX,
+            _ => return false,
+        };
+
+        self.scroll_logs(scroll);
+        true
+    }
+
+    /// Scrolls the help popup to `scroll`, keeping its last binding at the
+    /// bottom at most.
+    fn scroll_help(&mut self, scroll: u16) {
+        let last = (Action::ALL.len() as u16).saturating_sub(self.help_height);
+        self.help_scroll = scroll.min(last);
     }
 
-    pub fn on_key(&mut self, c: char) {
-        match c {
-            'q' => {
+    pub fn set_help_height(&mut self, height: u16) {
+        self.help_height = height;
+        self.scroll_help(self.help_scroll);
+    }
+
+    /// Keys for the help popup, which scroll through the bindings.
+    fn on_help_action(&mut self, action: Action) -> bool {
+        let current = self.help_scroll;
+        let page = self.help_height.max(1);
+
+        let scroll = match action {
+            Action::Up => current.saturating_sub(1),
+            Action::Down => current.saturating_add(1),
+            Action::PageUp => current.saturating_sub(page),
+            Action::PageDown => current.saturating_add(page),
+            Action::Top => 0,
+            Action::Bottom => u16::MAX,
+            _ => return false,
+        };
+
+        self.scroll_help(scroll);
+        true
+    }
+
+    /// Keys for the visualizations screen, which step through the frames.
+    fn on_visualizations_action(&mut self, action: Action) -> bool {
+        let visualization = match &mut self.visualization {
+            Some(visualization) => visualization,
+            None => return false,
+        };
+
+        let last = visualization.frames.len().saturating_sub(1);
+        let frame = visualization.frame;
+
+        visualization.frame = match action {
+            Action::Up => frame.saturating_sub(1),
+            Action::Down => (frame + 1).min(last),
+            Action::PageUp => frame.saturating_sub(10),
+            Action::PageDown => (frame + 10).min(last),
+            Action::Top => 0,
+            Action::Bottom => last,
+            _ => return false,
+        };
+
+        true
+    }
+
+    pub fn on_action(&mut self, action: Action) {
+        if self.show_help && self.on_help_action(action) {
+            return;
+        }
+
+        let handled = match self.screen {
+            Screen::Logs => self.on_logs_action(action),
+            Screen::Visualizations => self.on_visualizations_action(action),
+            Screen::Days | Screen::Benchmarks => false,
+        };
+
+        if !handled {
+            self.on_days_action(action);
+        }
+    }
+
+    /// Keys for the days screen. Other screens fall back to these, so keys
+    /// that work everywhere are handled here too.
+    fn on_days_action(&mut self, action: Action) {
+        match action {
+            Action::Quit => {
                 self.should_quit = true;
             }
 
-            'o' => {
-                let item = self.day_table.current_item();
+            Action::Up => self.on_up(),
+
+            Action::Down => self.on_down(),
+
+            Action::PageUp => {
+                self.day_table.move_by(-self.page_size());
+                self.detail_scroll = 0;
+            }
+
+            Action::PageDown => {
+                self.day_table.move_by(self.page_size());
+                self.detail_scroll = 0;
+            }
+
+            Action::Top => {
+                self.day_table.first();
+                self.detail_scroll = 0;
+            }
+
+            Action::Bottom => {
+                self.day_table.last();
+                self.detail_scroll = 0;
+            }
+
+            Action::ToggleDetail => self.on_enter(),
 
-                Command::new("open")
-                    .arg(item.url())
-                    .output()
-                    .expect("FAILED TO OPEN");
+            Action::ScrollLeft => {
+                self.answer_scroll = self.answer_scroll.saturating_sub(1);
             }
 
-            's' => {
-                let item = self.day_table.current_item();
-                item.part_1.solve();
-                item.part_2.solve();
+            Action::ScrollRight => {
+                self.answer_scroll += 1;
             }
 
-            'S' => {
-                for item in &self.day_table.items {
-                    item.part_1.solve();
-                    item.part_2.solve();
+            Action::NextScreen => self.show_screen(self.screen.offset(1)),
+
+            Action::PreviousScreen => self.show_screen(self.screen.offset(-1)),
+
+            Action::ShowDays => self.show_screen(Screen::Days),
+
+            Action::ShowBenchmarks => self.show_screen(Screen::Benchmarks),
+
+            Action::ShowLogs => self.show_screen(Screen::Logs),
+
+            Action::ShowVisualizations => self.show_screen(Screen::Visualizations),
+
+            Action::OpenPuzzle => {
+                if let Some(day) = self.day_table.current_item() {
+                    self.open(day.url());
                 }
             }
+
+            Action::OpenInput => {
+                if let Some(day) = self.day_table.current_item() {
+                    self.open(day.input_url());
+                }
+            }
+
+            Action::OpenInputFile => {
+                if let Some(day) = self.day_table.current_item() {
+                    let path = day.part_1.path().to_string();
+
+                    match fs::canonicalize(&path) {
+                        Ok(full_path) => self.open(full_path.display().to_string()),
+                        Err(e) => self.set_status(format!("Failed to open {}: {}", path, e)),
+                    }
+                }
+            }
+
+            Action::Solve => self.solve(self.selected_parts(), false),
+
+            Action::SolvePart1 => self.solve(self.selected_part(1), false),
+
+            Action::SolvePart2 => self.solve(self.selected_part(2), false),
+
+            Action::SolveAll => self.solve(self.all_parts(), false),
+
+            Action::Resolve => self.solve(self.selected_parts(), true),
+
+            Action::ResolveAll => self.solve(self.all_parts(), true),
+
+            Action::CopyPart1 => self.copy_answer(1),
+
+            Action::CopyPart2 => self.copy_answer(2),
+
+            Action::EditInput => {
+                if let Some(i) = self.day_table.current_index() {
+                    self.screen = Screen::Days;
+                    self.editor = Some(Editor::new(i));
+                    self.set_status(String::from(
+                        "Editing input: ctrl-r runs, ctrl-s saves, esc closes",
+                    ));
+                }
+            }
+
+            Action::Help => {
+                self.show_help = !self.show_help;
+                self.help_scroll = 0;
+            }
+
+            Action::Filter => {
+                self.filter.editing = true;
+            }
+
+            Action::CycleFilter => {
+                self.filter.quick = self.filter.quick.next();
+            }
+
+            Action::CycleSort => {
+                self.sort = self.sort.next();
+            }
+
+            Action::Cancel => {
+                if self.show_help {
+                    self.show_help = false;
+                } else if self.screen != Screen::Days {
+                    self.screen = Screen::Days;
+                } else if self.show_detail {
+                    self.on_enter();
+                } else if self.filter.is_active() {
+                    self.filter = Filter::new(self.filter.slow);
+                }
+            }
+        }
+
+        // The selected day may have changed under the visualization.
+        if self.screen == Screen::Visualizations {
+            self.load_visualization();
+        }
+    }
+
+    fn on_filter_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.filter.query.push(c);
+            }
+
+            KeyCode::Backspace => {
+                self.filter.query.pop();
+            }
+
+            KeyCode::Enter => {
+                self.filter.editing = false;
+            }
+
+            KeyCode::Esc => {
+                self.filter.query.clear();
+                self.filter.editing = false;
+            }
+
+            _ => {}
+        }
+    }
+
+    fn on_editor_key(&mut self, key: KeyEvent) {
+        let editor = match self.editor.as_mut() {
+            Some(editor) => editor,
+            None => return,
+        };
+        let day = &self.day_table.items[editor.item];
+
+        if key.modifiers.contains(KeyModifiers::CONTROL) {
+            match key.code {
+                KeyCode::Char('r') => editor.run(day),
+
+                KeyCode::Char('s') => {
+                    let status = match editor.save(day) {
+                        Ok(path) => format!("Saved input to {}", path.display()),
+                        Err(e) => format!("Failed to save input: {}", e),
+                    };
+                    self.set_status(status);
+                }
+
+                KeyCode::Char('c') => {
+                    self.should_quit = true;
+                }
+
+                _ => {}
+            }
+            return;
+        }
+
+        match key.code {
+            KeyCode::Char(c) => editor.insert(c),
+            KeyCode::Enter => editor.newline(),
+            KeyCode::Backspace => editor.backspace(),
+            KeyCode::Delete => editor.delete(),
+            KeyCode::Left => editor.left(),
+            KeyCode::Right => editor.right(),
+            KeyCode::Up => editor.up(),
+            KeyCode::Down => editor.down(),
+            KeyCode::Home => editor.home(),
+            KeyCode::End => editor.end(),
+            KeyCode::Esc => self.editor = None,
             _ => {}
         }
     }
 
-    pub fn on_tick(&mut self) {}
+    /// Handles text pasted into the terminal, which goes to whatever is
+    /// being typed into.
+    pub fn on_paste(&mut self, text: String) {
+        if let Some(editor) = self.editor.as_mut() {
+            editor.paste(&text);
+        } else if self.filter.editing {
+            self.filter
+                .query
+                .push_str(text.lines().next().unwrap_or_default());
+            self.arrange_days();
+        }
+    }
+
+    /// Re-applies the filter, since both the query and the state of the days
+    /// it matches on can change with any event.
+    fn arrange_days(&mut self) {
+        let (filter, sort) = (&self.filter, &self.sort);
+        self.day_table
+            .arrange(|day| filter.matches(day), |a, b| sort.compare(a, b));
+    }
+
+    pub fn on_key(&mut self, key: KeyEvent) {
+        if self.editor.is_some() {
+            self.on_editor_key(key);
+        } else if self.filter.editing {
+            self.on_filter_key(key);
+        } else if let Some(action) = self.keymap.action(key) {
+            self.on_action(action);
+        }
+
+        self.arrange_days();
+    }
+
+    pub fn on_tick(&mut self) {
+        if let Some(status) = &self.status {
+            if status.created.elapsed() >= STATUS_TTL {
+                self.status = None;
+            }
+        }
+    }
 }
@@ -0,0 +1,73 @@
+use crate::config::ClipboardConfig;
+
+use std::{
+    io::{self, Write},
+    process::{Command, Stdio},
+};
+
+fn osc52_sequence(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", base64::encode(text))
+}
+
+fn copy_osc52(text: &str) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    stdout.write_all(osc52_sequence(text).as_bytes())?;
+    stdout.flush()
+}
+
+fn copy_command(command: &str, text: &str) -> io::Result<()> {
+    let mut parts = command.split_whitespace();
+    let program = parts
+        .next()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "empty clipboard command"))?;
+
+    let mut child = Command::new(program)
+        .args(parts)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(text.as_bytes())?;
+    }
+
+    let status = child.wait()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::other(format!(
+            "`{}` exited with {}",
+            command, status
+        )))
+    }
+}
+
+/// Copies `text` to the system clipboard with the OSC 52 escape and the
+/// configured clipboard command, whichever are enabled. Writing the escape
+/// can't tell whether the terminal honoured it, so when both are used the
+/// command's result is the one reported.
+pub fn copy(config: &ClipboardConfig, text: &str) -> io::Result<()> {
+    let osc52 = config.osc52.then(|| copy_osc52(text));
+    let command = config
+        .command
+        .as_deref()
+        .map(|command| copy_command(command, text));
+
+    match (osc52, command) {
+        (_, Some(result)) | (Some(result), None) => result,
+        (None, None) => Err(io::Error::other(
+            "OSC 52 is disabled and no clipboard command is configured",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn osc52_sequence_encodes_text() {
+        assert_eq!(osc52_sequence("CMZ"), "\x1b]52;c;Q01a\x07");
+    }
+}
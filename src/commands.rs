@@ -0,0 +1,267 @@
+use crate::days::{init_days, Day, Parsing, Severity};
+
+use argh::FromArgs;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{
+    error::Error,
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+#[derive(Debug, FromArgs)]
+#[argh(subcommand)]
+pub enum Command {
+    Run(RunCommand),
+    Gen(GenCommand),
+    DiffTest(DiffTestCommand),
+    Lint(LintCommand),
+}
+
+impl Command {
+    pub fn execute(self) -> Result<(), Box<dyn Error>> {
+        match self {
+            Command::Run(command) => command.execute(),
+            Command::Gen(command) => command.execute(),
+            Command::DiffTest(command) => command.execute(),
+            Command::Lint(command) => command.execute(),
+        }
+    }
+}
+
+fn seeded_rng(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    }
+}
+
+fn find_day(days: &[Day], advent_day: u8) -> Result<&Day, Box<dyn Error>> {
+    days.iter()
+        .find(|day| day.advent_day() == advent_day)
+        .ok_or_else(|| format!("Day {} is not registered", advent_day).into())
+}
+
+/// Reads `path`, or stdin when it is `-`.
+fn read_input(path: &PathBuf) -> io::Result<String> {
+    if path.as_os_str() == "-" {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path)
+    }
+}
+
+/// Solve one part with any input file, printing the answer to stdout.
+#[derive(Debug, FromArgs)]
+#[argh(subcommand, name = "run")]
+pub struct RunCommand {
+    /// the day to solve.
+    #[argh(option)]
+    day: u8,
+    /// the part to solve, 1 or 2.
+    #[argh(option, default = "1")]
+    part: u8,
+    /// path to the input, or `-` to read stdin.
+    #[argh(option)]
+    input: PathBuf,
+    /// fail on the first malformed input line instead of skipping it.
+    #[argh(switch)]
+    strict: bool,
+}
+
+impl RunCommand {
+    fn execute(self) -> Result<(), Box<dyn Error>> {
+        if !(1..=2).contains(&self.part) {
+            return Err(format!("Part must be 1 or 2, not {}", self.part).into());
+        }
+
+        let mut days = init_days();
+        if self.strict {
+            days.iter_mut()
+                .for_each(|day| day.set_parsing(Parsing::Strict));
+        }
+        let problem = find_day(&days, self.day)?.part(self.part);
+        let input = read_input(&self.input)
+            .map_err(|e| format!("Could not read {}: {}", self.input.display(), e))?;
+
+        let solution = problem
+            .solve_input(&input)
+            .ok_or_else(|| format!("Day {} Part {} is not implemented", self.day, self.part))?
+            .map_err(|e| format!("Day {} Part {}: {}", self.day, self.part, e))?;
+
+        println!("{}", solution.art.as_ref().unwrap_or(&solution.answer));
+        if solution.art.is_some() {
+            println!("{}", solution.answer);
+        }
+        eprintln!(
+            "Solved Day {} Part {} in {:?}",
+            self.day, self.part, solution.elapsed
+        );
+        Ok(())
+    }
+}
+
+/// Print a random valid input for a day.
+#[derive(Debug, FromArgs)]
+#[argh(subcommand, name = "gen")]
+pub struct GenCommand {
+    /// the day to generate an input for.
+    #[argh(option)]
+    day: u8,
+    /// how large the input is, e.g. the number of elves or moves.
+    #[argh(option, default = "100")]
+    size: usize,
+    /// seed for reproducible inputs.
+    #[argh(option)]
+    seed: Option<u64>,
+}
+
+impl GenCommand {
+    fn execute(self) -> Result<(), Box<dyn Error>> {
+        let days = init_days();
+        let day = find_day(&days, self.day)?;
+        let mut rng = seeded_rng(self.seed);
+
+        let input = day
+            .generate(&mut rng, self.size)
+            .ok_or_else(|| format!("Day {} has no generator", self.day))?;
+        print!("{}", input);
+        Ok(())
+    }
+}
+
+/// Compare a day's solvers with its naive reference solvers on random inputs.
+#[derive(Debug, FromArgs)]
+#[argh(subcommand, name = "diff-test")]
+pub struct DiffTestCommand {
+    /// the day to test.
+    #[argh(option)]
+    day: u8,
+    /// how many inputs to try.
+    #[argh(option, default = "1000")]
+    count: usize,
+    /// the largest input size; each input gets a random size up to this.
+    #[argh(option, default = "100")]
+    size: usize,
+    /// seed for reproducible inputs.
+    #[argh(option)]
+    seed: Option<u64>,
+    /// where to save the first input the solvers disagree on, by default
+    /// `diff_failure.txt` in the day's input directory.
+    #[argh(option)]
+    out: Option<PathBuf>,
+}
+
+impl DiffTestCommand {
+    fn execute(self) -> Result<(), Box<dyn Error>> {
+        let mut days = init_days();
+        // Generated inputs are well formed, so they are parsed strictly to
+        // catch generators and parsers disagreeing too.
+        days.iter_mut()
+            .for_each(|day| day.set_parsing(Parsing::Strict));
+        let day = find_day(&days, self.day)?;
+        if !day.has_generator() {
+            return Err(format!("Day {} has no generator", self.day).into());
+        }
+        if !day.parts().iter().any(|problem| problem.has_reference()) {
+            return Err(format!("Day {} has no reference solvers", self.day).into());
+        }
+
+        let mut rng = seeded_rng(self.seed);
+        for _ in 0..self.count {
+            let size = rng.gen_range(1..=self.size.max(1));
+            let input = day.generate(&mut rng, size).unwrap_or_default();
+
+            for (part, problem) in (1..=2).zip(day.parts()) {
+                if let Some((answer, expected)) = problem.compare_with_reference(&input) {
+                    let out = self
+                        .out
+                        .unwrap_or_else(|| day.input_dir().join("diff_failure.txt"));
+                    fs::write(&out, &input)
+                        .map_err(|e| format!("Could not write {}: {}", out.display(), e))?;
+
+                    return Err(format!(
+                        "Day {} Part {} answered {:?} but the reference answered {:?}, input saved to {}",
+                        self.day,
+                        part,
+                        answer,
+                        expected,
+                        out.display()
+                    )
+                    .into());
+                }
+            }
+        }
+
+        println!("Day {}: {} inputs agree", self.day, self.count);
+        Ok(())
+    }
+}
+
+/// Check that an input has the puzzle's shape without solving it.
+#[derive(Debug, FromArgs)]
+#[argh(subcommand, name = "lint")]
+pub struct LintCommand {
+    /// the day the input is for.
+    #[argh(option)]
+    day: u8,
+    /// path to the input, or `-` to read stdin. Defaults to the day's input
+    /// files.
+    #[argh(option)]
+    input: Option<PathBuf>,
+}
+
+impl LintCommand {
+    fn execute(self) -> Result<(), Box<dyn Error>> {
+        let days = init_days();
+        let day = find_day(&days, self.day)?;
+        if !day.has_linter() {
+            return Err(format!("Day {} has no linter", self.day).into());
+        }
+
+        let mut paths: Vec<PathBuf> = match self.input {
+            Some(input) => vec![input],
+            None => day
+                .parts()
+                .iter()
+                .map(|p| PathBuf::from(p.path()))
+                .collect(),
+        };
+        paths.dedup();
+
+        let mut errors = 0;
+        for path in paths {
+            let input = read_input(&path)
+                .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+            let findings = day.lint(&input).unwrap_or_default();
+
+            for finding in &findings {
+                let problem = &finding.problem;
+                println!(
+                    "{}:{}:{}: {}: {} in {:?}",
+                    path.display(),
+                    problem.line,
+                    problem.column,
+                    finding.severity,
+                    problem.message,
+                    problem.excerpt
+                );
+            }
+            if findings.is_empty() {
+                println!("{}: no problems found", path.display());
+            }
+            errors += findings
+                .iter()
+                .filter(|f| f.severity == Severity::Error)
+                .count();
+        }
+
+        match errors {
+            0 => Ok(()),
+            1 => Err(format!("Found 1 error in the Day {} input", self.day).into()),
+            _ => Err(format!("Found {} errors in the Day {} input", errors, self.day).into()),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use crate::days::Parsing;
+use crate::keymap::{Action, KeyBinding};
+use crate::theme::ThemeName;
+
+use serde::Deserialize;
+use std::{collections::HashMap, error::Error, fs, io::ErrorKind, path::Path, thread};
+
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// One of `dark`, `light` or `high-contrast`.
+    pub theme: ThemeName,
+    pub clipboard: ClipboardConfig,
+    /// Command used to open URLs and files, tried before `$BROWSER`,
+    /// `xdg-open` and `open`.
+    pub opener: Option<String>,
+    /// Keys for each action, e.g. `solve = ["s", "enter"]`. Listing an action
+    /// replaces all of its default keys.
+    pub keys: HashMap<Action, Vec<KeyBinding>>,
+    /// Days with a part slower than this are shown by the "slow" filter.
+    pub slow_threshold_ms: u64,
+    /// Time the whole set of solutions should finish in, drawn on the
+    /// runtime chart.
+    pub runtime_budget_ms: u64,
+    /// Threads used when solving every day, defaulting to one per CPU.
+    pub threads: Option<usize>,
+    /// Fail on the first malformed input line instead of skipping it.
+    pub strict: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            theme: ThemeName::default(),
+            clipboard: ClipboardConfig::default(),
+            opener: None,
+            keys: HashMap::new(),
+            slow_threshold_ms: 100,
+            runtime_budget_ms: 1000,
+            threads: None,
+            strict: false,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct ClipboardConfig {
+    /// Copy using the OSC 52 terminal escape, which also works over SSH.
+    pub osc52: bool,
+    /// Command that receives the copied text on stdin, e.g. `xclip -selection clipboard`.
+    /// Run whenever it is set, alongside OSC 52 when that is enabled.
+    pub command: Option<String>,
+}
+
+impl Default for ClipboardConfig {
+    fn default() -> Self {
+        Self {
+            osc52: true,
+            command: None,
+        }
+    }
+}
+
+impl Config {
+    /// Loads the config at `path`, falling back to the defaults when the file
+    /// does not exist.
+    pub fn load(path: &Path) -> Result<Config, Box<dyn Error>> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Ok(toml::from_str(&contents)?),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn threads(&self) -> usize {
+        self.threads
+            .or_else(|| thread::available_parallelism().ok().map(|n| n.get()))
+            .unwrap_or(1)
+            .max(1)
+    }
+
+    pub fn parsing(&self) -> Parsing {
+        if self.strict {
+            Parsing::Strict
+        } else {
+            Parsing::Lenient
+        }
+    }
+}
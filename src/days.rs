@@ -4,38 +4,442 @@ mod day_3;
 mod day_4;
 mod day_5;
 mod day_6;
+// Stubs until day 7 is solved; the day is registered without solvers so it
+// counts as unimplemented.
+#[allow(dead_code)]
 mod day_7;
 
-use once_cell::unsync::OnceCell;
-use std::fs;
+use crate::ocr;
+
+use rand::rngs::StdRng;
+use serde::Deserialize;
+use std::{
+    error::Error,
+    fmt, fs,
+    io::{self, ErrorKind},
+    panic::{self, UnwindSafe},
+    path::{Path, PathBuf},
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Why a solver could not give an answer, e.g. because its input is not
+/// shaped like the puzzle's.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SolveError(String);
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> Self {
+        SolveError(message.into())
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Error for SolveError {}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> Self {
+        SolveError(e.to_string())
+    }
+}
+
+pub type SolveResult = Result<String, SolveError>;
+
+/// Where and why a line of input could not be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// The 1-based line number.
+    pub line: usize,
+    /// The 1-based column, counted in chars.
+    pub column: usize,
+    /// The start of the line, shortened when it is long.
+    pub excerpt: String,
+    pub message: String,
+}
+
+impl ParseError {
+    const EXCERPT_LENGTH: usize = 40;
+
+    /// An error at byte `index` of `text`, which is line `line` of the input.
+    pub fn new(line: usize, text: &str, index: usize, message: impl Into<String>) -> Self {
+        let column = text.char_indices().take_while(|(i, _)| *i < index).count() + 1;
+        let mut excerpt: String = text.chars().take(Self::EXCERPT_LENGTH).collect();
+        if excerpt.len() < text.len() {
+            excerpt.push('…');
+        }
+
+        ParseError {
+            line,
+            column,
+            excerpt,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Line {}, column {}: {} in {:?}",
+            self.line, self.column, self.message, self.excerpt
+        )
+    }
+}
 
-type SolverFn = fn(&str) -> String;
+impl Error for ParseError {}
+
+/// What solvers do with input lines they can't parse.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Parsing {
+    /// Skip them, or count them as zero.
+    #[default]
+    Lenient,
+    /// Fail with the first of them.
+    Strict,
+}
+
+impl Parsing {
+    /// Collects the lines that parsed. In strict mode the first line that
+    /// didn't is returned as an error instead.
+    pub fn collect<T>(
+        self,
+        lines: impl IntoIterator<Item = Result<T, ParseError>>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut parsed = Vec::new();
+        for line in lines {
+            match line {
+                Ok(item) => parsed.push(item),
+                Err(e) if self == Parsing::Strict => return Err(e),
+                Err(_) => {}
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    /// The input looks odd, but at least one part can still be solved.
+    Warning,
+    /// The input isn't shaped like the puzzle's.
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Severity::Warning => f.write_str("warning"),
+            Severity::Error => f.write_str("error"),
+        }
+    }
+}
+
+/// Something a day's linter noticed about an input line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finding {
+    pub severity: Severity,
+    pub problem: ParseError,
+}
+
+impl Finding {
+    pub fn warning(problem: ParseError) -> Self {
+        Finding {
+            severity: Severity::Warning,
+            problem,
+        }
+    }
+
+    pub fn error(problem: ParseError) -> Self {
+        Finding {
+            severity: Severity::Error,
+            problem,
+        }
+    }
+}
+
+type SolverFn = fn(&str, Parsing) -> SolveResult;
+type ReferenceFn = fn(&str) -> SolveResult;
+type LinterFn = fn(&str) -> Vec<Finding>;
+type VisualizerFn = fn(&str) -> Vec<String>;
+type GeneratorFn = fn(&mut StdRng, usize) -> String;
+
+pub struct Solution {
+    pub answer: String,
+    /// The solver's output when it drew the answer as letters, which
+    /// `answer` holds the recognized text of.
+    pub art: Option<String>,
+    pub elapsed: Duration,
+}
+
+impl Solution {
+    fn new(output: String, elapsed: Duration) -> Self {
+        if output.trim().contains('\n') {
+            if let Some(answer) = ocr::recognize(&output) {
+                return Self {
+                    answer,
+                    art: Some(output),
+                    elapsed,
+                };
+            }
+        }
+
+        Self {
+            answer: output,
+            art: None,
+            elapsed,
+        }
+    }
+
+    /// Lines to show in the detail view, with any letter art below the
+    /// recognized text.
+    pub fn lines(&self) -> Vec<&str> {
+        let mut lines: Vec<&str> = self.answer.lines().collect();
+        if let Some(art) = &self.art {
+            lines.extend(art.lines());
+        }
+        lines
+    }
+}
+
+pub enum Status {
+    Unsolved,
+    Solved(Solution),
+    Failed(String),
+}
+
+/// Whether a part's solver gives the expected answers for the day's examples.
+pub enum Examples {
+    /// Not checked yet, or no example has an answer for the part.
+    Unchecked,
+    Passed(usize),
+    /// Describes the first example that gave the wrong answer.
+    Failed(String),
+}
+
+/// Expected answers stored in a TOML file, e.g. `part_1 = "24000"`.
+#[derive(Default, Deserialize)]
+pub struct Answers {
+    part_1: Option<String>,
+    part_2: Option<String>,
+}
+
+impl Answers {
+    /// Reads the answers at `path`, which has none when it does not exist.
+    fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(answers) => {
+                toml::from_str(&answers).map_err(|e| io::Error::new(ErrorKind::InvalidData, e))
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            _ => self.part_2.as_deref(),
+        }
+    }
+}
+
+/// An example input from the puzzle text, stored as
+/// `inputs/day_N/example*.txt` with its answers in a `.toml` file of the
+/// same name.
+pub struct Example {
+    pub path: PathBuf,
+    pub input: String,
+    answers: Answers,
+}
+
+impl Example {
+    fn load(path: PathBuf) -> io::Result<Self> {
+        let input = fs::read_to_string(&path)?;
+        let answers = Answers::load(&path.with_extension("toml"))?;
+
+        Ok(Example {
+            path,
+            input,
+            answers,
+        })
+    }
+
+    pub fn answer(&self, part: u8) -> Option<&str> {
+        self.answers.part(part)
+    }
+}
+
+/// Runs `f`, turning a panic into its message.
+pub fn catch_panic<T>(f: impl FnOnce() -> T + UnwindSafe) -> Result<T, String> {
+    panic::catch_unwind(f).map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| String::from("solver panicked"))
+    })
+}
+
+fn describe_output(output: Result<SolveResult, String>) -> String {
+    match output {
+        Ok(Ok(answer)) => answer,
+        Ok(Err(e)) => format!("error: {}", e),
+        Err(message) => format!("panic: {}", message),
+    }
+}
 
 pub struct Problem {
     path: String,
     solver: Option<SolverFn>,
-    solution: OnceCell<String>,
+    /// A deliberately naive solver that `solver` is checked against.
+    reference: Option<ReferenceFn>,
+    parsing: Parsing,
+    status: Status,
+    examples: Examples,
 }
 
 impl Problem {
-    fn read_input(&self) -> Option<String> {
-        fs::read_to_string(&self.path).ok()
+    pub fn read_input(&self) -> io::Result<String> {
+        fs::read_to_string(&self.path)
     }
 
-    pub fn solve(&self) {
-        if let Some(solver) = self.solver {
-            if let Some(input) = self.read_input() {
-                if self.solution.get().is_none() {
-                    self.solution
-                        .set(solver(&input))
-                        .expect("Tried to double-set solution");
-                }
+    /// Runs the solver unless the problem already has a result, returning
+    /// whether it ran. A solver that panics is recorded as failed.
+    pub fn solve(&mut self) -> bool {
+        if !matches!(self.status, Status::Unsolved) {
+            return false;
+        }
+
+        if !self.is_implemented() {
+            return false;
+        }
+
+        self.status = match self.read_input() {
+            Ok(input) => match catch_panic(|| self.solve_input(&input)) {
+                Ok(Some(Ok(solution))) => Status::Solved(solution),
+                Ok(Some(Err(e))) => Status::Failed(e.to_string()),
+                Ok(None) => Status::Unsolved,
+                Err(message) => Status::Failed(message),
+            },
+            Err(e) => Status::Failed(format!("Could not read {}: {}", self.path, e)),
+        };
+        true
+    }
+
+    /// Runs the solver on `input` instead of the problem's input file,
+    /// leaving the problem's status alone.
+    pub fn solve_input(&self, input: &str) -> Option<Result<Solution, SolveError>> {
+        let solver = self.solver?;
+        let start = Instant::now();
+        let output = solver(input, self.parsing);
+
+        Some(output.map(|output| Solution::new(output, start.elapsed())))
+    }
+
+    pub fn has_reference(&self) -> bool {
+        self.reference.is_some()
+    }
+
+    /// Runs the solver and the reference solver on `input`, returning both
+    /// answers when they differ. Both failing counts as agreeing, but a panic
+    /// never does.
+    pub fn compare_with_reference(&self, input: &str) -> Option<(String, String)> {
+        let (solver, reference) = (self.solver?, self.reference?);
+        let answer = catch_panic(|| solver(input, self.parsing));
+        let expected = catch_panic(|| reference(input));
+
+        let agree = match (&answer, &expected) {
+            (Ok(Ok(a)), Ok(Ok(b))) => a == b,
+            (Ok(Err(_)), Ok(Err(_))) => true,
+            _ => false,
+        };
+        if agree {
+            None
+        } else {
+            Some((describe_output(answer), describe_output(expected)))
+        }
+    }
+
+    /// Runs the solver on every example with an answer for `part`.
+    pub fn check_examples(&self, examples: &[Example], part: u8) -> Examples {
+        let mut passed = 0;
+
+        for example in examples {
+            let expected = match example.answer(part) {
+                Some(expected) => expected,
+                None => continue,
+            };
+            let answer = match self.solve_input(&example.input) {
+                Some(Ok(solution)) => solution.answer,
+                Some(Err(e)) => format!("error: {}", e),
+                None => return Examples::Unchecked,
+            };
+
+            if answer != expected {
+                return Examples::Failed(format!(
+                    "{}: expected {}, got {}",
+                    example.path.display(),
+                    expected,
+                    answer
+                ));
             }
+            passed += 1;
+        }
+
+        if passed == 0 {
+            Examples::Unchecked
+        } else {
+            Examples::Passed(passed)
         }
     }
 
+    pub fn examples(&self) -> &Examples {
+        &self.examples
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn reset(&mut self) {
+        self.status = Status::Unsolved;
+        self.examples = Examples::Unchecked;
+    }
+
+    pub fn is_implemented(&self) -> bool {
+        self.solver.is_some()
+    }
+
+    pub fn status(&self) -> &Status {
+        &self.status
+    }
+
     pub fn solution(&self) -> Option<String> {
-        return self.solution.get().map(|s| s.to_string());
+        match &self.status {
+            Status::Solved(solution) => Some(solution.answer.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn elapsed(&self) -> Option<Duration> {
+        match &self.status {
+            Status::Solved(solution) => Some(solution.elapsed),
+            _ => None,
+        }
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        match &self.status {
+            Status::Failed(error) => Some(error),
+            _ => None,
+        }
     }
 }
 
@@ -52,6 +456,13 @@ pub struct Day {
     correct: Correct,
     pub part_1: Problem,
     pub part_2: Problem,
+    visualizer: Option<VisualizerFn>,
+    generator: Option<GeneratorFn>,
+    linter: Option<LinterFn>,
+}
+
+fn input_dir(advent_day: u8) -> PathBuf {
+    PathBuf::from(format!("./inputs/day_{}", advent_day))
 }
 
 fn input_path(advent_day: u8, input_part: u8) -> String {
@@ -72,14 +483,81 @@ impl Day {
             part_1: Problem {
                 path: input_path(advent_day, 1),
                 solver: first_solver,
-                solution: OnceCell::new(),
+                reference: None,
+                parsing: Parsing::default(),
+                status: Status::Unsolved,
+                examples: Examples::Unchecked,
             },
             part_2: Problem {
                 path: input_path(advent_day, 2),
                 solver: second_solver,
-                solution: OnceCell::new(),
+                reference: None,
+                parsing: Parsing::default(),
+                status: Status::Unsolved,
+                examples: Examples::Unchecked,
             },
             correct,
+            visualizer: None,
+            generator: None,
+            linter: None,
+        }
+    }
+
+    /// Adds a function that renders the part 1 input as a series of frames
+    /// for the visualization screen.
+    pub fn with_visualizer(mut self, visualizer: VisualizerFn) -> Self {
+        self.visualizer = Some(visualizer);
+        self
+    }
+
+    /// Adds naive solvers that the real ones are checked against.
+    pub fn with_references(mut self, first: ReferenceFn, second: ReferenceFn) -> Self {
+        self.part_1.reference = Some(first);
+        self.part_2.reference = Some(second);
+        self
+    }
+
+    /// Adds a function that produces random valid inputs of roughly the
+    /// given size.
+    pub fn with_generator(mut self, generator: GeneratorFn) -> Self {
+        self.generator = Some(generator);
+        self
+    }
+
+    /// Adds a function that checks an input's shape without solving it.
+    pub fn with_linter(mut self, linter: LinterFn) -> Self {
+        self.linter = Some(linter);
+        self
+    }
+
+    pub fn advent_day(&self) -> u8 {
+        self.advent_day
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn parts(&self) -> [&Problem; 2] {
+        [&self.part_1, &self.part_2]
+    }
+
+    pub fn part(&self, part: u8) -> &Problem {
+        match part {
+            1 => &self.part_1,
+            _ => &self.part_2,
+        }
+    }
+
+    pub fn set_parsing(&mut self, parsing: Parsing) {
+        self.part_1.parsing = parsing;
+        self.part_2.parsing = parsing;
+    }
+
+    pub fn part_mut(&mut self, part: u8) -> &mut Problem {
+        match part {
+            1 => &mut self.part_1,
+            _ => &mut self.part_2,
         }
     }
 
@@ -91,15 +569,147 @@ impl Day {
         format!("https://adventofcode.com/2022/day/{}", self.advent_day)
     }
 
-    pub fn stars(&self) -> String {
+    pub fn input_url(&self) -> String {
+        format!("{}/input", self.url())
+    }
+
+    pub fn input_dir(&self) -> PathBuf {
+        input_dir(self.advent_day)
+    }
+
+    /// The accepted answers for the real inputs, recorded in
+    /// `inputs/day_N/answers.toml`.
+    pub fn answers(&self) -> io::Result<Answers> {
+        Answers::load(&self.input_dir().join("answers.toml"))
+    }
+
+    /// Loads the day's examples in file name order.
+    pub fn examples(&self) -> io::Result<Vec<Example>> {
+        let entries = match fs::read_dir(self.input_dir()) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(e),
+        };
+
+        let mut paths = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            let name = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or_default();
+            if name.starts_with("example") && name.ends_with(".txt") {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        paths.into_iter().map(Example::load).collect()
+    }
+
+    pub fn check_examples(&mut self, part: u8) {
+        let result = match self.examples() {
+            Ok(examples) => self.part(part).check_examples(&examples, part),
+            Err(e) => Examples::Failed(format!("Could not read examples: {}", e)),
+        };
+        self.part_mut(part).examples = result;
+    }
+
+    pub fn has_visualizer(&self) -> bool {
+        self.visualizer.is_some()
+    }
+
+    pub fn visualize(&self) -> Option<Vec<String>> {
+        let input = self.part_1.read_input().ok()?;
+        self.visualize_input(&input)
+    }
+
+    pub fn visualize_input(&self, input: &str) -> Option<Vec<String>> {
+        let visualizer = self.visualizer?;
+        Some(visualizer(input))
+    }
+
+    pub fn has_generator(&self) -> bool {
+        self.generator.is_some()
+    }
+
+    pub fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        let generator = self.generator?;
+        Some(generator(rng, size))
+    }
+
+    pub fn has_linter(&self) -> bool {
+        self.linter.is_some()
+    }
+
+    /// Checks `input` with the day's linter, finding every malformed line
+    /// rather than just the first.
+    pub fn lint(&self, input: &str) -> Option<Vec<Finding>> {
+        let linter = self.linter?;
+        if input.trim().is_empty() {
+            let empty = ParseError::new(1, "", 0, "the input is empty");
+            return Some(vec![Finding::error(empty)]);
+        }
+        Some(linter(input))
+    }
+
+    pub fn star_count(&self) -> usize {
         match self.correct {
-            Correct::None => String::from(""),
-            Correct::PartOne => String::from("*"),
-            Correct::Both => String::from("**"),
+            Correct::None => 0,
+            Correct::PartOne => 1,
+            Correct::Both => 2,
         }
     }
 }
 
+/// Solves the `(index, part)` pairs in `targets`, handing whole days out to
+/// up to `threads` threads. Returns the pairs whose solvers ran, in order.
+pub fn solve_parallel(
+    days: &mut [Day],
+    targets: &[(usize, u8)],
+    threads: usize,
+) -> Vec<(usize, u8)> {
+    let queue: Vec<(usize, &mut Day, Vec<u8>)> = days
+        .iter_mut()
+        .enumerate()
+        .filter_map(|(i, day)| {
+            let parts: Vec<u8> = targets
+                .iter()
+                .filter(|(target, _)| *target == i)
+                .map(|(_, part)| *part)
+                .collect();
+            (!parts.is_empty()).then_some((i, day, parts))
+        })
+        .collect();
+
+    let threads = threads.clamp(1, queue.len().max(1));
+    let queue = Mutex::new(queue.into_iter());
+    let solved = Mutex::new(Vec::new());
+
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| loop {
+                let next = queue.lock().unwrap().next();
+                let (i, day, parts) = match next {
+                    Some(next) => next,
+                    None => return,
+                };
+
+                for part in parts {
+                    if day.part_mut(part).solve() {
+                        day.check_examples(part);
+                        solved.lock().unwrap().push((i, part));
+                    }
+                }
+            });
+        }
+    });
+
+    let mut solved = solved.into_inner().unwrap();
+    solved.sort_unstable();
+    solved
+}
+
 pub fn init_days() -> Vec<Day> {
     vec![
         Day::new(
@@ -108,48 +718,332 @@ pub fn init_days() -> Vec<Day> {
             Some(day_1::solve_1),
             Some(day_1::solve_2),
             Correct::Both,
-        ),
+        )
+        .with_references(day_1::reference_1, day_1::reference_2)
+        .with_generator(day_1::generate)
+        .with_linter(day_1::lint),
         Day::new(
             2,
             String::from("Rock Paper Scissors"),
             Some(day_2::solve_1),
             Some(day_2::solve_2),
             Correct::Both,
-        ),
+        )
+        .with_references(day_2::reference_1, day_2::reference_2)
+        .with_generator(day_2::generate)
+        .with_linter(day_2::lint),
         Day::new(
             3,
             String::from("Rucksack Reorganization"),
             Some(day_3::solve_1),
             Some(day_3::solve_2),
             Correct::Both,
-        ),
+        )
+        .with_references(day_3::reference_1, day_3::reference_2)
+        .with_generator(day_3::generate)
+        .with_linter(day_3::lint),
         Day::new(
             4,
             String::from("Camp Cleanup"),
             Some(day_4::solve_1),
             Some(day_4::solve_2),
             Correct::Both,
-        ),
+        )
+        .with_references(day_4::reference_1, day_4::reference_2)
+        .with_generator(day_4::generate)
+        .with_linter(day_4::lint),
         Day::new(
             5,
             String::from("Supply Stacks"),
             Some(day_5::solve_1),
             Some(day_5::solve_2),
             Correct::Both,
-        ),
+        )
+        .with_visualizer(day_5::visualize)
+        .with_references(day_5::reference_1, day_5::reference_2)
+        .with_generator(day_5::generate)
+        .with_linter(day_5::lint),
         Day::new(
             6,
             String::from("Tuning Trouble"),
             Some(day_6::solve_1),
             Some(day_6::solve_2),
             Correct::Both,
-        ),
-        Day::new(
-            7,
-            String::from("TBD"),
-            Some(day_7::solve_1),
-            Some(day_7::solve_2),
-            Correct::None,
-        ),
+        )
+        .with_references(day_6::reference_1, day_6::reference_2)
+        .with_generator(day_6::generate)
+        .with_linter(day_6::lint),
+        Day::new(7, String::from("TBD"), None, None, Correct::None),
     ]
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn letter_art_answers_are_recognized() {
+        let art = "\
+.##..###.
+#..#.#..#
+#..#.###.
+####.#..#
+#..#.#..#
+#..#.###.
+";
+        let solution = Solution::new(art.to_string(), Duration::ZERO);
+        assert_eq!(solution.answer, "AB");
+        assert_eq!(solution.lines().len(), 7);
+
+        let solution = Solution::new(String::from("one\ntwo"), Duration::ZERO);
+        assert_eq!(solution.answer, "one\ntwo");
+        assert!(solution.art.is_none());
+    }
+
+    #[test]
+    fn every_day_passes_its_examples() {
+        let mut checked = 0;
+
+        for mut day in init_days() {
+            day.set_parsing(Parsing::Strict);
+            let examples = day.examples().unwrap();
+            for part in 1..=2 {
+                match day.part(part).check_examples(&examples, part) {
+                    Examples::Passed(count) => checked += count,
+                    Examples::Failed(error) => panic!("Day {}: {}", day.advent_day(), error),
+                    Examples::Unchecked => {}
+                }
+            }
+        }
+
+        assert!(checked > 0, "no examples were checked");
+    }
+
+    #[test]
+    fn generated_inputs_can_be_solved() {
+        for mut day in init_days() {
+            day.set_parsing(Parsing::Strict);
+            for seed in 0..20 {
+                let mut rng = StdRng::seed_from_u64(seed);
+                let input = match day.generate(&mut rng, seed as usize * 10) {
+                    Some(input) => input,
+                    None => break,
+                };
+
+                for problem in day.parts() {
+                    let answer = problem.solve_input(&input).and_then(Result::ok);
+                    assert!(
+                        answer.is_some_and(|s| !s.answer.is_empty()),
+                        "Day {} gave no answer for seed {}:\n{}",
+                        day.advent_day(),
+                        seed,
+                        input
+                    );
+                }
+            }
+        }
+    }
+
+    /// The day's example inputs, followed by one generated input per size.
+    fn sample_inputs(day: &Day, seed: u64, sizes: impl Iterator<Item = usize>) -> Vec<String> {
+        let mut inputs: Vec<String> = day
+            .examples()
+            .unwrap()
+            .into_iter()
+            .map(|example| example.input)
+            .collect();
+        let mut rng = StdRng::seed_from_u64(seed);
+        inputs.extend(sizes.filter_map(|size| day.generate(&mut rng, size)));
+        inputs
+    }
+
+    #[test]
+    fn solvers_agree_with_references() {
+        for day in init_days() {
+            for input in sample_inputs(&day, 47, 1..50) {
+                for problem in day.parts() {
+                    assert_eq!(
+                        problem.compare_with_reference(&input),
+                        None,
+                        "Day {} disagrees on:\n{}",
+                        day.advent_day(),
+                        input
+                    );
+                }
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn days_do_not_panic_on_arbitrary_input(
+            input in prop_oneof![
+                any::<Vec<u8>>().prop_map(|bytes| String::from_utf8_lossy(&bytes).into_owned()),
+                "([0-9]{0,12}|[A-Za-zé!]{0,20}|move [0-9] from [0-9] to [0-9]|[ \\[\\],-]{0,8}|\n){0,30}",
+            ],
+            parsing in prop_oneof![Just(Parsing::Lenient), Just(Parsing::Strict)],
+        ) {
+            for mut day in init_days() {
+                day.set_parsing(parsing);
+                for problem in day.parts() {
+                    let _ = problem.solve_input(&input);
+                }
+                let _ = day.visualize_input(&input);
+                let _ = day.lint(&input);
+            }
+        }
+    }
+
+    #[test]
+    fn strict_parsing_reports_the_first_malformed_line() {
+        let cases = [
+            (1, "1000\n20x0\n\n3000\n", "3000", "Line 2, column 3:"),
+            (
+                1,
+                "1000\n99999999999\n\n3000\n",
+                "3000",
+                "Line 2, column 1: number is too large",
+            ),
+            (2, "A Y\nB\nC Z\n", "14", "Line 2, column 1:"),
+            (
+                3,
+                "vJrwpWtwJgWrhcsFMMfFFhFp\nabc\n",
+                "16",
+                "Line 2, column 4:",
+            ),
+            (4, "2-4,6-8\n2-3\n5-7,7-9\n", "0", "Line 2, column 4:"),
+            (
+                5,
+                "    [D]\n[N] [C]\n 1   2\n\nmove 1 from 2 to 1\nmove one from 1 to 2\n",
+                "DC",
+                "Line 6, column 1:",
+            ),
+            (
+                6,
+                "mjqjpqmgbljsphdztnvjfqwrcgsmlb\nx\n",
+                "7",
+                "Line 2, column 1:",
+            ),
+        ];
+
+        for (advent_day, input, lenient, strict) in cases {
+            let mut day = init_days()
+                .into_iter()
+                .find(|day| day.advent_day() == advent_day)
+                .unwrap();
+            match day.part_1.solve_input(input) {
+                Some(Ok(solution)) => assert_eq!(solution.answer, lenient, "Day {}", advent_day),
+                _ => panic!("Day {} should skip the malformed line", advent_day),
+            }
+
+            day.set_parsing(Parsing::Strict);
+            match day.part_1.solve_input(input) {
+                Some(Err(e)) => assert!(
+                    e.to_string().starts_with(strict),
+                    "Day {}: {}",
+                    advent_day,
+                    e
+                ),
+                _ => panic!("Day {} should reject the malformed line", advent_day),
+            }
+        }
+    }
+
+    #[test]
+    fn parse_errors_shorten_long_lines() {
+        let line = "a".repeat(50) + "é";
+        let error = ParseError::new(3, &line, 50, "expected a number");
+
+        assert_eq!((error.line, error.column), (3, 51));
+        assert_eq!(error.excerpt, "a".repeat(40) + "…");
+    }
+
+    #[test]
+    fn examples_and_generated_inputs_lint_cleanly() {
+        for day in init_days().into_iter().filter(|day| day.has_linter()) {
+            for input in sample_inputs(&day, 50, (5..100).step_by(5)) {
+                assert_eq!(
+                    day.lint(&input),
+                    Some(vec![]),
+                    "Day {}:\n{}",
+                    day.advent_day(),
+                    input
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn lint_reports_every_malformed_line() {
+        use Severity::{Error, Warning};
+        let cases = [
+            (
+                3,
+                "vJrwpWtwJgWrhcsFMMfFFhFp\nab1d\nabc\nabcd\n",
+                vec![(2, Error), (3, Error), (4, Warning), (4, Warning)],
+            ),
+            (
+                5,
+                "    [D]    \n[N] [C]    \n[Z] [M] [P] [Q]\n 1   2   3\n\n\
+                 move 1 from 2 to 1\nmove 3 from 1 to 4\nmove 5 from 1 to 3\n\
+                 move 1 from 2 to 2\nmove x\n",
+                vec![(3, Error), (7, Error), (8, Error), (9, Error), (10, Error)],
+            ),
+            (6, "abc\n", vec![(1, Error)]),
+            (6, "abcabcabcabcabcabc\n", vec![(1, Error)]),
+            (6, "abcdabcdabcdabcd\n", vec![(1, Warning)]),
+            (1, "\n", vec![(1, Error)]),
+        ];
+
+        for (advent_day, input, expected) in cases {
+            let days = init_days();
+            let day = days
+                .iter()
+                .find(|day| day.advent_day() == advent_day)
+                .unwrap();
+            let findings: Vec<(usize, Severity)> = day
+                .lint(input)
+                .unwrap()
+                .into_iter()
+                .map(|finding| (finding.problem.line, finding.severity))
+                .collect();
+
+            assert_eq!(findings, expected, "Day {}", advent_day);
+        }
+    }
+
+    #[test]
+    fn solver_panics_are_recorded_as_failures() {
+        let mut day = Day::new(
+            1,
+            String::from("Panics"),
+            Some(|_, _| panic!("boom")),
+            None,
+            Correct::None,
+        );
+
+        assert!(day.part_1.solve());
+        assert!(matches!(day.part_1.status(), Status::Failed(message) if message == "boom"));
+    }
+
+    #[test]
+    fn solve_parallel_matches_solving_in_order() {
+        let mut serial = init_days();
+        let mut parallel = init_days();
+        let targets: Vec<(usize, u8)> = (0..serial.len())
+            .flat_map(|i| [(i, 1), (i, 2)])
+            .filter(|&(i, part)| serial[i].parts()[part as usize - 1].is_implemented())
+            .collect();
+
+        let solved = solve_parallel(&mut serial, &targets, 1);
+        assert_eq!(solve_parallel(&mut parallel, &targets, 4), solved);
+        assert_eq!(solved.len(), targets.len());
+
+        for (a, b) in serial.iter().zip(&parallel) {
+            assert_eq!(a.part_1.solution(), b.part_1.solution());
+            assert_eq!(a.part_2.solution(), b.part_2.solution());
+        }
+    }
+}
@@ -1,75 +1,119 @@
+use super::{Finding, ParseError, Parsing, SolveError, SolveResult};
 use itertools::Itertools;
+use rand::{rngs::StdRng, Rng};
 
 // PART 1
 
-fn sum_lines(lines: Vec<&str>) -> u32 {
-    return lines
-        .iter()
-        .map(|l| str::parse::<u32>(l).unwrap_or(0))
-        .sum();
+fn checked_sum(calories: impl IntoIterator<Item = u32>) -> Result<u32, SolveError> {
+    calories
+        .into_iter()
+        .try_fold(0u32, |total, c| total.checked_add(c))
+        .ok_or_else(|| SolveError::new("Calorie total is too large"))
 }
 
-fn sorted_sums(input: &str) -> Vec<u32> {
-    let mut groups: Vec<Vec<&str>> = Vec::new();
-    for (key, group) in &input.lines().group_by(|l| !l.is_empty()) {
+fn parse_calories(number: usize, line: &str) -> Result<u32, ParseError> {
+    line.parse().map_err(|_| {
+        let (index, message) = match line.find(|c: char| !c.is_ascii_digit()) {
+            None if !line.is_empty() => (0, "number is too large"),
+            index => (index.unwrap_or(0), "expected a number of calories"),
+        };
+        ParseError::new(number, line, index, message)
+    })
+}
+
+fn sum_lines(lines: Vec<(usize, &str)>, parsing: Parsing) -> Result<u32, SolveError> {
+    let calories = parsing.collect(lines.iter().map(|(i, l)| parse_calories(i + 1, l)))?;
+    checked_sum(calories)
+}
+
+fn sorted_sums(input: &str, parsing: Parsing) -> Result<Vec<u32>, SolveError> {
+    let mut groups: Vec<Vec<(usize, &str)>> = Vec::new();
+    for (key, group) in &input.lines().enumerate().group_by(|(_, l)| !l.is_empty()) {
         if key {
             groups.push(group.collect());
         }
     }
 
     let mut sums: Vec<u32> = groups
-        .iter()
-        .map(|lines| sum_lines(lines.to_vec()))
-        .collect();
+        .into_iter()
+        .map(|lines| sum_lines(lines, parsing))
+        .collect::<Result<_, _>>()?;
 
     sums.sort();
-    sums
+    Ok(sums)
 }
 
-pub fn solve_1(input: &str) -> String {
-    let sums = sorted_sums(input);
-    return sums.last().unwrap_or(&0).to_string();
+pub fn solve_1(input: &str, parsing: Parsing) -> SolveResult {
+    let sums = sorted_sums(input, parsing)?;
+    Ok(sums.last().unwrap_or(&0).to_string())
 }
 
 // PART 2
 
-pub fn solve_2(input: &str) -> String {
-    let sums = sorted_sums(input);
-    let total: u32 = sums.iter().rev().take(3).sum();
+pub fn solve_2(input: &str, parsing: Parsing) -> SolveResult {
+    let sums = sorted_sums(input, parsing)?;
+    let total = checked_sum(sums.iter().rev().take(3).copied())?;
 
-    total.to_string()
+    Ok(total.to_string())
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    const TEST_INPUT: &str = r#"
-1000
-2000
-3000
+// LINT
+
+/// Reports every line that isn't a number of calories.
+pub fn lint(input: &str) -> Vec<Finding> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.is_empty())
+        .filter_map(|(i, l)| parse_calories(i + 1, l).err())
+        .map(Finding::error)
+        .collect()
+}
 
-4000
+// REFERENCE
 
-5000
-6000
+fn reference_sums(input: &str) -> Vec<u32> {
+    input
+        .split("\n\n")
+        .map(|group| group.lines().map(|l| l.parse::<u32>().unwrap_or(0)).sum())
+        .collect()
+}
 
-7000
-8000
-9000
+/// Naive version of `solve_1` for differential testing.
+pub fn reference_1(input: &str) -> SolveResult {
+    let mut most = 0;
+    for sum in reference_sums(input) {
+        if sum > most {
+            most = sum;
+        }
+    }
+    Ok(most.to_string())
+}
 
-10000
-"#;
+/// Naive version of `solve_2`, taking the largest sum three times.
+pub fn reference_2(input: &str) -> SolveResult {
+    let mut sums = reference_sums(input);
+    let mut total = 0;
 
-    #[test]
-    fn solve_1_correct() {
-        let result = solve_1(TEST_INPUT);
-        assert_eq!(result, "24000");
+    for _ in 0..3 {
+        let largest = (0..sums.len()).max_by_key(|&i| sums[i]);
+        if let Some(i) = largest {
+            total += sums.remove(i);
+        }
     }
+    Ok(total.to_string())
+}
 
-    #[test]
-    fn solve_2_correct() {
-        let result = solve_2(TEST_INPUT);
-        assert_eq!(result, "45000");
-    }
+// GENERATOR
+
+/// Generates `size` elves, each carrying a few snacks.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            (0..rng.gen_range(1..=6))
+                .map(|_| rng.gen_range(1000..=60000).to_string())
+                .join("\n")
+        })
+        .join("\n\n")
+        + "\n"
 }
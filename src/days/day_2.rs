@@ -1,12 +1,35 @@
+use super::{Finding, ParseError, Parsing, SolveResult};
 use itertools::Itertools;
+use rand::{rngs::StdRng, Rng};
 
 // PART 1
 
-fn parse_input(input: &str) -> Vec<(&str, &str)> {
-    return input
-        .lines()
-        .filter_map(|line| line.split(' ').collect_tuple())
-        .collect();
+fn parse_line(number: usize, line: &str) -> Result<(&str, &str), ParseError> {
+    let error = |index, message| ParseError::new(number, line, index, message);
+
+    let (challenger, code) = line
+        .split(' ')
+        .collect_tuple()
+        .ok_or_else(|| error(0, "expected two codes separated by a space"))?;
+
+    if challenger_throw(challenger).is_none() {
+        return Err(error(0, "expected A, B or C"));
+    }
+    if player_throw(code).is_none() {
+        return Err(error(challenger.len() + 1, "expected X, Y or Z"));
+    }
+
+    Ok((challenger, code))
+}
+
+fn parse_input(input: &str, parsing: Parsing) -> Result<Vec<(&str, &str)>, ParseError> {
+    parsing.collect(
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(i, line)| parse_line(i + 1, line)),
+    )
 }
 
 #[derive(Debug)]
@@ -78,8 +101,8 @@ fn round_score(outcome: Outcome) -> u32 {
     }
 }
 
-pub fn solve_1(input: &str) -> String {
-    let plays = parse_input(input);
+pub fn solve_1(input: &str, parsing: Parsing) -> SolveResult {
+    let plays = parse_input(input, parsing)?;
 
     let score: u32 = plays
         .iter()
@@ -87,7 +110,7 @@ pub fn solve_1(input: &str) -> String {
         .map(round_score)
         .sum();
 
-    score.to_string()
+    Ok(score.to_string())
 }
 
 // PART 2
@@ -128,8 +151,8 @@ fn desired_outcome(challenger_code: &str, outcome_code: &str) -> Option<Outcome>
     Some(result)
 }
 
-pub fn solve_2(input: &str) -> String {
-    let plays = parse_input(input);
+pub fn solve_2(input: &str, parsing: Parsing) -> SolveResult {
+    let plays = parse_input(input, parsing)?;
 
     let score: u32 = plays
         .iter()
@@ -137,28 +160,76 @@ pub fn solve_2(input: &str) -> String {
         .map(round_score)
         .sum();
 
-    score.to_string()
+    Ok(score.to_string())
+}
+
+// LINT
+
+/// Reports every line that isn't a pair of codes like `A Y`.
+pub fn lint(input: &str) -> Vec<Finding> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.is_empty())
+        .filter_map(|(i, l)| parse_line(i + 1, l).err())
+        .map(Finding::error)
+        .collect()
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
+// REFERENCE
 
-    const TEST_INPUT: &str = r#"
-A Y
-B X
-C Z
-"#;
+/// Naive version of `solve_1`, scoring every round from a table.
+pub fn reference_1(input: &str) -> SolveResult {
+    let total: u32 = input
+        .lines()
+        .map(|round| match round {
+            "A X" => 4,
+            "A Y" => 8,
+            "A Z" => 3,
+            "B X" => 1,
+            "B Y" => 5,
+            "B Z" => 9,
+            "C X" => 7,
+            "C Y" => 2,
+            "C Z" => 6,
+            _ => 0,
+        })
+        .sum();
+    Ok(total.to_string())
+}
 
-    #[test]
-    fn solve_1_correct() {
-        let result = solve_1(TEST_INPUT);
-        assert_eq!(result, "15");
-    }
+/// Naive version of `solve_2`, scoring every round from a table.
+pub fn reference_2(input: &str) -> SolveResult {
+    let total: u32 = input
+        .lines()
+        .map(|round| match round {
+            "A X" => 3,
+            "A Y" => 4,
+            "A Z" => 8,
+            "B X" => 1,
+            "B Y" => 5,
+            "B Z" => 9,
+            "C X" => 2,
+            "C Y" => 6,
+            "C Z" => 7,
+            _ => 0,
+        })
+        .sum();
+    Ok(total.to_string())
+}
 
-    #[test]
-    fn solve_2_correct() {
-        let result = solve_2(TEST_INPUT);
-        assert_eq!(result, "12");
-    }
+// GENERATOR
+
+/// Generates a strategy guide with `size` rounds.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            format!(
+                "{} {}",
+                ['A', 'B', 'C'][rng.gen_range(0..3)],
+                ['X', 'Y', 'Z'][rng.gen_range(0..3)]
+            )
+        })
+        .join("\n")
+        + "\n"
 }
@@ -1,4 +1,6 @@
+use super::{Finding, ParseError, Parsing, SolveError, SolveResult};
 use itertools::Itertools;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
 use std::collections::HashMap;
 
 // PART 1
@@ -10,18 +12,22 @@ struct Sack<'a> {
 }
 
 fn split_line(line: &str) -> Option<(&str, &str)> {
-    let len = line.len();
+    let len = line.chars().count();
 
     if len == 0 {
         None
     } else {
-        let half_idx = len / 2;
+        // Split on a char boundary, since the line may not be ASCII.
+        let half_idx = line
+            .char_indices()
+            .nth(len / 2)
+            .map_or(line.len(), |(i, _)| i);
         Some((&line[0..half_idx], &line[half_idx..]))
     }
 }
 
 impl<'a> Sack<'a> {
-    fn new(contents: &str) -> Sack {
+    fn new(contents: &str) -> Sack<'_> {
         let compartments = split_line(contents);
 
         Sack {
@@ -32,12 +38,40 @@ impl<'a> Sack<'a> {
     }
 }
 
-fn parse_input(input: &str) -> Vec<Sack> {
-    return input
+/// Checks that a sack holds only items, split evenly between its
+/// compartments.
+fn check_sack(number: usize, line: &str) -> Result<(), ParseError> {
+    if let Some(index) = line.find(|c: char| !c.is_ascii_alphabetic()) {
+        return Err(ParseError::new(
+            number,
+            line,
+            index,
+            "expected an item, a-z or A-Z",
+        ));
+    }
+    if !line.len().is_multiple_of(2) {
+        return Err(ParseError::new(
+            number,
+            line,
+            line.len(),
+            "expected an even number of items",
+        ));
+    }
+    Ok(())
+}
+
+fn parse_input(input: &str, parsing: Parsing) -> Result<Vec<Sack<'_>>, ParseError> {
+    input
         .lines()
-        .filter(|l| !l.is_empty())
-        .map(Sack::new)
-        .collect();
+        .enumerate()
+        .filter(|(_, l)| !l.is_empty())
+        .map(|(i, l)| {
+            if parsing == Parsing::Strict {
+                check_sack(i + 1, l)?;
+            }
+            Ok(Sack::new(l))
+        })
+        .collect()
 }
 
 fn first_common_char(strings: Vec<&str>) -> Option<char> {
@@ -65,26 +99,26 @@ fn find_duplicate_item(sack: &Sack) -> Option<char> {
     first_common_char(strings)
 }
 
-fn priority(item: char) -> u32 {
+fn priority(item: char) -> Result<u32, SolveError> {
     let ascii_val = item as u32;
 
-    if item.is_lowercase() {
-        ascii_val - 96
-    } else {
-        ascii_val - 38
+    match item {
+        'a'..='z' => Ok(ascii_val - 96),
+        'A'..='Z' => Ok(ascii_val - 38),
+        _ => Err(SolveError::new(format!("{:?} is not an item", item))),
     }
 }
 
-pub fn solve_1(input: &str) -> String {
-    let sacks = parse_input(input);
+pub fn solve_1(input: &str, parsing: Parsing) -> SolveResult {
+    let sacks = parse_input(input, parsing)?;
 
     let dupe_priority_sum: u32 = sacks
         .iter()
         .filter_map(find_duplicate_item)
         .map(priority)
-        .sum();
+        .sum::<Result<_, _>>()?;
 
-    dupe_priority_sum.to_string()
+    Ok(dupe_priority_sum.to_string())
 }
 
 // PART 2
@@ -94,8 +128,8 @@ fn find_badge(group: &[Sack]) -> Option<char> {
     first_common_char(strings)
 }
 
-pub fn solve_2(input: &str) -> String {
-    let sacks = parse_input(input);
+pub fn solve_2(input: &str, parsing: Parsing) -> SolveResult {
+    let sacks = parse_input(input, parsing)?;
 
     let sack_groups: Vec<Vec<Sack>> = sacks
         .into_iter()
@@ -108,33 +142,125 @@ pub fn solve_2(input: &str) -> String {
         .iter()
         .filter_map(|group| find_badge(group))
         .map(priority)
-        .sum();
+        .sum::<Result<_, _>>()?;
 
-    badge_priority_sum.to_string()
+    Ok(badge_priority_sum.to_string())
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
+// LINT
+
+/// Reports sacks that hold something other than items or can't be split
+/// evenly, and warns about sacks without a misplaced item and elves that
+/// don't make up whole groups.
+pub fn lint(input: &str) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let sacks: Vec<(usize, &str)> = input
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.is_empty())
+        .map(|(i, l)| (i + 1, l))
+        .collect();
 
-    const TEST_INPUT: &str = r#"
-vJrwpWtwJgWrhcsFMMfFFhFp
-jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-PmmdzqPrVvPwwTWBwg
-wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-ttgJtRGJQctTZtZT
-CrZsJsPPZsGzwwsLwLmpwMDw
-"#;
+    for &(number, line) in &sacks {
+        match check_sack(number, line) {
+            Err(e) => findings.push(Finding::error(e)),
+            Ok(()) if find_duplicate_item(&Sack::new(line)).is_none() => {
+                let message = "no item is in both compartments";
+                findings.push(Finding::warning(ParseError::new(number, line, 0, message)));
+            }
+            Ok(()) => {}
+        }
+    }
 
-    #[test]
-    fn solve_1_correct() {
-        let result = solve_1(TEST_INPUT);
-        assert_eq!(result, "157");
+    if let Some(&(number, line)) = sacks.last().filter(|_| !sacks.len().is_multiple_of(3)) {
+        let message = format!("{} sacks don't split into groups of three", sacks.len());
+        findings.push(Finding::warning(ParseError::new(number, line, 0, message)));
     }
+    findings
+}
+
+// REFERENCE
 
-    #[test]
-    fn solve_2_correct() {
-        let result = solve_2(TEST_INPUT);
-        assert_eq!(result, "70");
+fn reference_priority(item: char) -> u32 {
+    ('a'..='z')
+        .chain('A'..='Z')
+        .position(|c| c == item)
+        .map_or(0, |i| i as u32 + 1)
+}
+
+/// Naive version of `solve_1`, comparing every pair of items.
+pub fn reference_1(input: &str) -> SolveResult {
+    let mut total = 0;
+
+    for line in input.lines() {
+        let items: Vec<char> = line.chars().collect();
+        let (first, second) = items.split_at(items.len() / 2);
+
+        if let Some(item) = first.iter().find(|item| second.contains(item)) {
+            total += reference_priority(*item);
+        }
     }
+    Ok(total.to_string())
+}
+
+/// Naive version of `solve_2`, checking every item of the first elf in each
+/// group against the other two.
+pub fn reference_2(input: &str) -> SolveResult {
+    let lines: Vec<&str> = input.lines().collect();
+    let mut total = 0;
+
+    for group in lines.chunks(3) {
+        if let [a, b, c] = group {
+            if let Some(item) = a.chars().find(|&item| b.contains(item) && c.contains(item)) {
+                total += reference_priority(item);
+            }
+        }
+    }
+    Ok(total.to_string())
+}
+
+// GENERATOR
+
+fn random_items(rng: &mut StdRng, pool: &[char], count: usize) -> Vec<char> {
+    (0..count)
+        .map(|_| *pool.choose(rng).unwrap_or(&'a'))
+        .collect()
+}
+
+/// Generates rucksacks for at least `size` elves, in groups of three. Each
+/// rucksack has exactly one item in both compartments, and each group shares
+/// exactly one badge.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    let mut sacks = Vec::new();
+
+    for _ in 0..size.div_ceil(3) {
+        items.shuffle(rng);
+        let badge = items[0];
+
+        // Each elf in the group packs from its own 17 item types, so the
+        // badge is the only one they all carry.
+        for pool in items[1..].chunks(17) {
+            let (first, second) = pool.split_at(8);
+            let shared = *first.choose(rng).unwrap_or(&badge);
+            let half = rng.gen_range(4..=16);
+
+            let mut compartment_1 = random_items(rng, first, half - 2);
+            compartment_1.extend([shared, badge]);
+            compartment_1.shuffle(rng);
+
+            let mut compartment_2 = random_items(rng, second, half - 1);
+            compartment_2.push(shared);
+            compartment_2.shuffle(rng);
+
+            sacks.push(
+                compartment_1
+                    .into_iter()
+                    .chain(compartment_2)
+                    .collect::<String>(),
+            );
+        }
+    }
+
+    sacks.join("\n") + "\n"
 }
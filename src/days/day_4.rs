@@ -1,6 +1,8 @@
 // PART 1
 
+use super::{Finding, ParseError, Parsing, SolveResult};
 use itertools::Itertools;
+use rand::{rngs::StdRng, Rng};
 
 struct Assignment {
     min: u32,
@@ -21,37 +23,52 @@ impl Assignment {
 
 type AssignmentPair = (Assignment, Assignment);
 
-fn parse_input_line(line: &str) -> Option<AssignmentPair> {
-    let (left, right) = line.split(',').collect_tuple()?;
-
-    let (left_min, left_max) = left
-        .split('-')
-        .filter_map(|x| x.parse().ok())
-        .collect_tuple()?;
+/// Parses a range like `2-4` found at byte `start` of `line`.
+fn parse_assignment(
+    number: usize,
+    line: &str,
+    start: usize,
+    text: &str,
+) -> Result<Assignment, ParseError> {
+    let error = |index, message: &str| ParseError::new(number, line, index, message);
+
+    let (min, max) = text
+        .split_once('-')
+        .ok_or_else(|| error(start + text.len(), "expected a range like 2-4"))?;
+    let min = min
+        .parse()
+        .map_err(|_| error(start, "expected a section number"))?;
+    let max = max
+        .parse()
+        .map_err(|_| error(start + text.len() - max.len(), "expected a section number"))?;
+
+    Ok(Assignment { min, max })
+}
 
-    let (right_min, right_max) = right
-        .split('-')
-        .filter_map(|x| x.parse().ok())
-        .collect_tuple()?;
+fn parse_input_line(number: usize, line: &str) -> Result<AssignmentPair, ParseError> {
+    let (left, right) = line.split_once(',').ok_or_else(|| {
+        ParseError::new(
+            number,
+            line,
+            line.len(),
+            "expected two ranges separated by a comma",
+        )
+    })?;
 
-    let la = Assignment {
-        min: left_min,
-        max: left_max,
-    };
-    let ra = Assignment {
-        min: right_min,
-        max: right_max,
-    };
+    let la = parse_assignment(number, line, 0, left)?;
+    let ra = parse_assignment(number, line, left.len() + 1, right)?;
 
-    Some((la, ra))
+    Ok((la, ra))
 }
 
-fn parse_input(input: &str) -> Vec<AssignmentPair> {
-    return input
-        .lines()
-        .filter(|l| !l.is_empty())
-        .filter_map(parse_input_line)
-        .collect();
+fn parse_input(input: &str, parsing: Parsing) -> Result<Vec<AssignmentPair>, ParseError> {
+    parsing.collect(
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.is_empty())
+            .map(|(i, l)| parse_input_line(i + 1, l)),
+    )
 }
 
 fn containing_pair(pair: &AssignmentPair) -> bool {
@@ -62,46 +79,141 @@ fn overlappying_pair(pair: &AssignmentPair) -> bool {
     pair.0.overlaps(&pair.1)
 }
 
-pub fn solve_1(input: &str) -> String {
-    let pairs = parse_input(input);
+pub fn solve_1(input: &str, parsing: Parsing) -> SolveResult {
+    let pairs = parse_input(input, parsing)?;
 
     let containing_pairs = pairs.iter().filter(|&pair| containing_pair(pair)).count();
 
-    containing_pairs.to_string()
+    Ok(containing_pairs.to_string())
 }
 
 // PART 2
 
-pub fn solve_2(input: &str) -> String {
-    let pairs = parse_input(input);
+pub fn solve_2(input: &str, parsing: Parsing) -> SolveResult {
+    let pairs = parse_input(input, parsing)?;
 
     let overlapping_pairs = pairs.iter().filter(|&pair| overlappying_pair(pair)).count();
 
-    overlapping_pairs.to_string()
+    Ok(overlapping_pairs.to_string())
+}
+
+// LINT
+
+/// Reports every line that isn't a pair of ranges, and warns about ranges
+/// that end before they start.
+pub fn lint(input: &str) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    for (i, line) in input.lines().enumerate().filter(|(_, l)| !l.is_empty()) {
+        let (left, right) = match parse_input_line(i + 1, line) {
+            Ok(pair) => pair,
+            Err(e) => {
+                findings.push(Finding::error(e));
+                continue;
+            }
+        };
+
+        let right_start = line.find(',').map_or(0, |comma| comma + 1);
+        for (assignment, start) in [(left, 0), (right, right_start)] {
+            if assignment.min > assignment.max {
+                let message = "range ends before it starts";
+                findings.push(Finding::warning(ParseError::new(
+                    i + 1,
+                    line,
+                    start,
+                    message,
+                )));
+            }
+        }
+    }
+    findings
+}
+
+// REFERENCE
+
+/// Lists every section in each assignment of a pair.
+fn reference_sections(line: &str) -> Option<(Vec<u32>, Vec<u32>)> {
+    let numbers: Vec<u32> = line
+        .split(['-', ','])
+        .map(|n| n.parse().ok())
+        .collect::<Option<Vec<u32>>>()?;
+
+    match numbers.as_slice() {
+        [a, b, c, d] => Some(((*a..=*b).collect(), (*c..=*d).collect())),
+        _ => None,
+    }
+}
+
+/// Naive version of `solve_1`, comparing the assignments section by section.
+pub fn reference_1(input: &str) -> SolveResult {
+    Ok(input
+        .lines()
+        .filter_map(reference_sections)
+        .filter(|(left, right)| {
+            left.iter().all(|s| right.contains(s)) || right.iter().all(|s| left.contains(s))
+        })
+        .count()
+        .to_string())
+}
+
+/// Naive version of `solve_2`, looking for any section in both assignments.
+pub fn reference_2(input: &str) -> SolveResult {
+    Ok(input
+        .lines()
+        .filter_map(reference_sections)
+        .filter(|(left, right)| left.iter().any(|s| right.contains(s)))
+        .count()
+        .to_string())
+}
+
+// GENERATOR
+
+fn random_sections(rng: &mut StdRng) -> (u32, u32) {
+    let start = rng.gen_range(1..=99);
+    (start, rng.gen_range(start..=99))
+}
+
+/// Generates `size` pairs of section assignments.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let (a, b) = random_sections(rng);
+            let (c, d) = random_sections(rng);
+            format!("{}-{},{}-{}", a, b, c, d)
+        })
+        .join("\n")
+        + "\n"
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use proptest::prelude::*;
+
+    fn sections() -> impl Strategy<Value = (u32, u32)> {
+        (1..100u32, 0..100u32).prop_map(|(min, len)| (min, min + len))
+    }
 
-    const TEST_INPUT: &str = r#"
-2-4,6-8
-2-3,4-5
-5-7,7-9
-2-8,3-7
-6-6,4-6
-2-6,4-8
-"#;
-
-    #[test]
-    fn solve_1_correct() {
-        let result = solve_1(TEST_INPUT);
-        assert_eq!(result, "2");
+    fn intersects(a: &Assignment, b: &Assignment) -> bool {
+        a.min <= b.max && b.min <= a.max
     }
 
-    #[test]
-    fn solve_2_correct() {
-        let result = solve_2(TEST_INPUT);
-        assert_eq!(result, "4");
+    proptest! {
+        #[test]
+        fn parses_rendered_pairs((a, b) in sections(), (c, d) in sections()) {
+            let (left, right) = parse_input_line(1, &format!("{}-{},{}-{}", a, b, c, d)).unwrap();
+            prop_assert_eq!((left.min, left.max, right.min, right.max), (a, b, c, d));
+        }
+
+        #[test]
+        fn containing_pairs_also_overlap((a, b) in sections(), (c, d) in sections()) {
+            let pair = (Assignment { min: a, max: b }, Assignment { min: c, max: d });
+
+            if containing_pair(&pair) {
+                prop_assert!(overlappying_pair(&pair));
+            }
+            prop_assert_eq!(pair.0.overlaps(&pair.1), intersects(&pair.0, &pair.1));
+            prop_assert_eq!(pair.0.overlaps(&pair.1), pair.1.overlaps(&pair.0));
+        }
     }
 }
@@ -1,8 +1,10 @@
 // PART 1
 
+use super::{Finding, ParseError, Parsing, SolveError, SolveResult};
 use itertools::Itertools;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
 use regex::Regex;
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt};
 
 #[derive(Debug)]
 struct Crate {
@@ -24,6 +26,12 @@ struct Instruction {
     dest: StackId,
 }
 
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.amount, self.src, self.dest)
+    }
+}
+
 #[derive(Debug)]
 struct CargoManifest {
     stacks: HashMap<StackId, SupplyStack>,
@@ -37,6 +45,27 @@ enum InstructionError {
     InvalidAmount(Instruction),
 }
 
+impl fmt::Display for InstructionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InstructionError::SrcNotFound(inst) => write!(f, "{}: no stack {}", inst, inst.src),
+            InstructionError::DestNotFound(inst) if inst.src == inst.dest => {
+                write!(f, "{}: moves crates onto the same stack", inst)
+            }
+            InstructionError::DestNotFound(inst) => write!(f, "{}: no stack {}", inst, inst.dest),
+            InstructionError::InvalidAmount(inst) => {
+                write!(f, "{}: not enough crates on stack {}", inst, inst.src)
+            }
+        }
+    }
+}
+
+impl From<InstructionError> for SolveError {
+    fn from(e: InstructionError) -> Self {
+        SolveError::new(e.to_string())
+    }
+}
+
 type InstructionResult = std::result::Result<(), InstructionError>;
 
 impl CargoManifest {
@@ -97,6 +126,29 @@ impl CargoManifest {
         Ok(())
     }
 
+    /// Draws the stacks in the same format as the puzzle input.
+    fn render_stacks(&self) -> String {
+        let stacks: Vec<&SupplyStack> = self.stacks.values().sorted_by_key(|s| s.id).collect();
+        let height = stacks.iter().map(|s| s.stack.len()).max().unwrap_or(0);
+
+        let mut lines: Vec<String> = (0..height)
+            .rev()
+            .map(|level| {
+                stacks
+                    .iter()
+                    .map(|s| match s.stack.get(level) {
+                        Some(c) => format!("[{}]", c.label),
+                        None => String::from("   "),
+                    })
+                    .join(" ")
+            })
+            .collect();
+
+        lines.push(stacks.iter().map(|s| format!(" {} ", s.id)).join(" "));
+        lines.join("\n")
+    }
+
+    #[allow(clippy::needless_return)]
     fn current_tops(&self) -> Vec<Option<&Crate>> {
         return self
             .stacks
@@ -107,14 +159,74 @@ impl CargoManifest {
     }
 }
 
-fn parse_stacks(lines: &[&str]) -> Option<HashMap<StackId, SupplyStack>> {
-    let stack_ids = lines.last()?;
+type NumberedLine<'a> = (usize, &'a str);
+
+/// Checks that the labels under the stack diagram count up from 1.
+fn check_labels(number: usize, line: &str) -> Result<(), ParseError> {
+    let labels = line.char_indices().filter(|(_, c)| !c.is_whitespace());
+
+    for (id, (index, label)) in labels.enumerate() {
+        if label.to_digit(10) != Some(id as u32 + 1) {
+            let message = format!("expected stack label {}", id + 1);
+            return Err(ParseError::new(number, line, index, message));
+        }
+    }
+    Ok(())
+}
+
+/// Checks that a row of the stack diagram holds nothing but crates like
+/// `[A]` above the stack labels at `id_indexes`.
+fn check_crate_row(number: usize, row: &str, id_indexes: &[usize]) -> Result<(), ParseError> {
+    let error = |index| ParseError::new(number, row, index, "expected a crate like [A]");
+    let bytes = row.as_bytes();
+    let first_non_space =
+        |from: usize, to: usize| (from..to.min(bytes.len())).find(|&i| bytes[i] != b' ');
+    let mut checked = 0;
+
+    for &idx in id_indexes {
+        let cell = idx.saturating_sub(1)..idx + 2;
+        if let Some(index) = first_non_space(checked, cell.start) {
+            return Err(error(index));
+        }
+        match bytes.get(cell.clone()) {
+            Some([b'[', label, b']']) if label.is_ascii_alphanumeric() => {}
+            Some(b"   ") => {}
+            Some(_) => return Err(error(cell.start)),
+            // The row ends before this cell, so the rest must be blank.
+            None => return first_non_space(checked, bytes.len()).map_or(Ok(()), |i| Err(error(i))),
+        }
+        checked = cell.end;
+    }
+
+    match first_non_space(checked, bytes.len()) {
+        Some(index) => {
+            let message = "crate isn't above a stack label";
+            Err(ParseError::new(number, row, index, message))
+        }
+        None => Ok(()),
+    }
+}
+
+fn parse_stacks(
+    lines: &[NumberedLine],
+    parsing: Parsing,
+) -> Result<HashMap<StackId, SupplyStack>, ParseError> {
+    let &(label_number, stack_ids) = lines
+        .last()
+        .ok_or_else(|| ParseError::new(1, "", 0, "expected a stack diagram"))?;
     let id_indexes: Vec<usize> = stack_ids
         .chars()
         .enumerate()
         .filter_map(|(i, c)| if c.is_whitespace() { None } else { Some(i) })
         .collect();
 
+    if parsing == Parsing::Strict {
+        check_labels(label_number, stack_ids)?;
+        for &(number, row) in &lines[..lines.len() - 1] {
+            check_crate_row(number, row, &id_indexes)?;
+        }
+    }
+
     let mut stacks: HashMap<StackId, SupplyStack> = HashMap::new();
 
     for (id, idx) in id_indexes.iter().enumerate() {
@@ -124,7 +236,7 @@ fn parse_stacks(lines: &[&str]) -> Option<HashMap<StackId, SupplyStack>> {
             .iter()
             .rev()
             .skip(1)
-            .filter_map(|line| match line.chars().nth(*idx) {
+            .filter_map(|(_, line)| match line.chars().nth(*idx) {
                 Some(label) if label.is_alphanumeric() => Some(Crate {
                     label: label.to_string(),
                 }),
@@ -141,60 +253,90 @@ fn parse_stacks(lines: &[&str]) -> Option<HashMap<StackId, SupplyStack>> {
         stacks.insert(stack_id, supply_stack);
     }
 
-    Some(stacks)
+    Ok(stacks)
 }
 
-fn parse_instructions(lines: Vec<&str>) -> Option<Vec<Instruction>> {
-    let r = Regex::new(r"^move (\d+) from (\d+) to (\d+)$").expect("Invalid regex");
+fn move_regex() -> Regex {
+    Regex::new(r"^move (\d+) from (\d+) to (\d+)$").expect("Invalid regex")
+}
+
+fn parse_move(r: &Regex, number: usize, line: &str) -> Result<Instruction, ParseError> {
+    let capture = r.captures(line).ok_or_else(|| {
+        ParseError::new(
+            number,
+            line,
+            0,
+            "expected a move like \"move 1 from 2 to 3\"",
+        )
+    })?;
+    let field = |i| {
+        let m = capture.get(i).expect("the regex has three groups");
+        m.as_str()
+            .parse()
+            .map_err(|_| ParseError::new(number, line, m.start(), "number is too large"))
+    };
+
+    Ok(Instruction {
+        amount: field(1)?,
+        src: field(2)?,
+        dest: field(3)?,
+    })
+}
+
+fn parse_instructions(
+    lines: &[NumberedLine],
+    parsing: Parsing,
+) -> Result<Vec<Instruction>, ParseError> {
+    let r = move_regex();
 
     let instructions = lines
         .iter()
-        .filter_map(|line| r.captures(line))
-        .filter_map(|capture| {
-            let amount = capture.get(1)?.as_str().parse().ok()?;
-            let src = capture.get(2)?.as_str().parse().ok()?;
-            let dest = capture.get(3)?.as_str().parse().ok()?;
-
-            Some(Instruction { amount, src, dest })
-        })
-        .collect();
+        .filter(|(_, line)| !line.is_empty())
+        .map(|&(number, line)| parse_move(&r, number, line));
 
-    Some(instructions)
+    parsing.collect(instructions)
 }
 
-fn parse_input(input: &str) -> Option<CargoManifest> {
-    let stack_diagram_lines: Vec<&str> = input
-        .lines()
-        .skip_while(|l| l.is_empty())
-        .take_while(|l| !l.is_empty())
-        .collect();
+/// Splits the input into the stack diagram and the moves below it.
+fn split_sections(input: &str) -> (Vec<NumberedLine<'_>>, Vec<NumberedLine<'_>>) {
+    let lines: Vec<NumberedLine> = input.lines().enumerate().map(|(i, l)| (i + 1, l)).collect();
 
-    let stacks = parse_stacks(&stack_diagram_lines)?;
+    let stack_diagram_lines: Vec<NumberedLine> = lines
+        .iter()
+        .copied()
+        .skip_while(|(_, l)| l.is_empty())
+        .take_while(|(_, l)| !l.is_empty())
+        .collect();
 
-    let instruction_lines: Vec<&str> = input
-        .lines()
-        .skip_while(|l| l.is_empty())
-        .skip_while(|l| !l.is_empty())
+    let instruction_lines: Vec<NumberedLine> = lines
+        .iter()
+        .copied()
+        .skip_while(|(_, l)| l.is_empty())
+        .skip_while(|(_, l)| !l.is_empty())
         .skip(1)
         .collect();
 
-    let instructions = parse_instructions(instruction_lines)?;
+    (stack_diagram_lines, instruction_lines)
+}
+
+fn parse_input(input: &str, parsing: Parsing) -> Result<CargoManifest, ParseError> {
+    let (stack_diagram_lines, instruction_lines) = split_sections(input);
+
+    let stacks = parse_stacks(&stack_diagram_lines, parsing)?;
+    let instructions = parse_instructions(&instruction_lines, parsing)?;
 
     let cm = CargoManifest {
         stacks,
         instructions,
     };
 
-    Some(cm)
+    Ok(cm)
 }
 
-pub fn solve_1(input: &str) -> String {
-    let mut cargo_manifest = parse_input(input).expect("Invalid input");
+pub fn solve_1(input: &str, parsing: Parsing) -> SolveResult {
+    let mut cargo_manifest = parse_input(input, parsing)?;
 
-    if let Err(e) = cargo_manifest.apply_instructions_part_1() {
-        eprintln!("error applying instructions: {:?} {:?}", e, cargo_manifest);
-        return String::from("ERROR");
-    }
+    cargo_manifest.apply_instructions_part_1()?;
 
     let tops: Vec<&String> = cargo_manifest
         .current_tops()
@@ -202,18 +344,15 @@ pub fn solve_1(input: &str) -> String {
         .filter_map(|crate_opt| crate_opt.as_ref().map(|c| &c.label))
         .collect();
 
-    return tops.iter().join("");
+    Ok(tops.iter().join(""))
 }
 
 // PART 2
 
-pub fn solve_2(input: &str) -> String {
-    let mut cargo_manifest = parse_input(input).expect("Invalid input");
+pub fn solve_2(input: &str, parsing: Parsing) -> SolveResult {
+    let mut cargo_manifest = parse_input(input, parsing)?;
 
-    if let Err(e) = cargo_manifest.apply_instructions_part_2() {
-        eprintln!("error applying instructions: {:?} {:?}", e, cargo_manifest);
-        return String::from("ERROR");
-    }
+    cargo_manifest.apply_instructions_part_2()?;
 
     let tops: Vec<&String> = cargo_manifest
         .current_tops()
@@ -221,34 +360,323 @@ pub fn solve_2(input: &str) -> String {
         .filter_map(|crate_opt| crate_opt.as_ref().map(|c| &c.label))
         .collect();
 
-    return tops.iter().join("");
+    Ok(tops.iter().join(""))
+}
+
+// VISUALIZATION
+
+/// One frame per instruction, showing the stacks as the part 1 crane moves
+/// crates one at a time.
+pub fn visualize(input: &str) -> Vec<String> {
+    let mut cargo_manifest = match parse_input(input, Parsing::Lenient) {
+        Ok(cargo_manifest) => cargo_manifest,
+        Err(_) => return vec![],
+    };
+
+    let instructions = std::mem::take(&mut cargo_manifest.instructions);
+    let mut frames = vec![format!("Start\n\n{}", cargo_manifest.render_stacks())];
+
+    for inst in instructions {
+        let caption = inst.to_string();
+
+        // Apply the instructions one by one so every step can be drawn.
+        cargo_manifest.instructions = vec![inst];
+        if let Err(e) = cargo_manifest.apply_instructions_part_1() {
+            frames.push(format!("{}\n\nerror: {}", caption, e));
+            break;
+        }
+
+        frames.push(format!("{}\n\n{}", caption, cargo_manifest.render_stacks()));
+    }
+
+    frames
+}
+
+// LINT
+
+/// Reports malformed diagram rows and moves, and moves that take crates
+/// from or to a stack that doesn't exist or holds too few of them. Moves
+/// onto the stack they come from are warned about.
+pub fn lint(input: &str) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let (diagram, moves) = split_sections(input);
+
+    let &(label_number, labels) = match diagram.last() {
+        Some(labels) => labels,
+        None => {
+            let missing = ParseError::new(1, "", 0, "expected a stack diagram");
+            return vec![Finding::error(missing)];
+        }
+    };
+    if let Err(e) = check_labels(label_number, labels) {
+        findings.push(Finding::error(e));
+    }
+
+    let id_indexes: Vec<usize> = labels
+        .chars()
+        .enumerate()
+        .filter_map(|(i, c)| if c.is_whitespace() { None } else { Some(i) })
+        .collect();
+    for &(number, row) in &diagram[..diagram.len() - 1] {
+        if let Err(e) = check_crate_row(number, row, &id_indexes) {
+            findings.push(Finding::error(e));
+        }
+    }
+
+    let mut heights: HashMap<StackId, u32> = match parse_stacks(&diagram, Parsing::Lenient) {
+        Ok(stacks) => stacks
+            .values()
+            .map(|s| (s.id, s.stack.len() as u32))
+            .collect(),
+        Err(e) => return vec![Finding::error(e)],
+    };
+
+    let r = move_regex();
+    for &(number, line) in moves.iter().filter(|(_, l)| !l.is_empty()) {
+        let inst = match parse_move(&r, number, line) {
+            Ok(inst) => inst,
+            Err(e) => {
+                findings.push(Finding::error(e));
+                continue;
+            }
+        };
+        let src_at = line.find(" from ").map_or(0, |i| i + 6);
+        let dest_at = line.rfind(" to ").map_or(0, |i| i + 4);
+        let error =
+            |index, message: String| Finding::error(ParseError::new(number, line, index, message));
+
+        let src_height = match heights.get(&inst.src) {
+            Some(&height) => height,
+            None => {
+                findings.push(error(src_at, format!("no stack {}", inst.src)));
+                continue;
+            }
+        };
+        if !heights.contains_key(&inst.dest) {
+            findings.push(error(dest_at, format!("no stack {}", inst.dest)));
+            continue;
+        }
+        if inst.src == inst.dest {
+            findings.push(error(
+                dest_at,
+                String::from("moves crates onto the same stack"),
+            ));
+            continue;
+        }
+        if inst.amount > src_height {
+            let message = format!("stack {} only holds {} crates", inst.src, src_height);
+            findings.push(error(0, message));
+        }
+
+        let moved = inst.amount.min(src_height);
+        heights.insert(inst.src, src_height - moved);
+        *heights.entry(inst.dest).or_default() += moved;
+    }
+
+    findings
+}
+
+// REFERENCE
+
+/// A move of `(amount, src, dest)`.
+type ReferenceMove = (usize, usize, usize);
+
+/// Reads the stacks bottom to top from the diagram's fixed-width columns,
+/// and the moves.
+fn reference_parse(input: &str) -> (Vec<Vec<char>>, Vec<ReferenceMove>) {
+    let (diagram, moves) = input.split_once("\n\n").unwrap_or((input, ""));
+    let mut rows: Vec<Vec<char>> = diagram.lines().map(|l| l.chars().collect()).collect();
+    let labels = rows.pop().unwrap_or_default();
+
+    let count = labels.iter().filter(|c| !c.is_whitespace()).count();
+    let mut stacks = vec![Vec::new(); count];
+    for row in rows.iter().rev() {
+        for (i, stack) in stacks.iter_mut().enumerate() {
+            match row.get(1 + 4 * i) {
+                Some(&c) if c.is_alphanumeric() => stack.push(c),
+                _ => {}
+            }
+        }
+    }
+
+    let moves = moves
+        .lines()
+        .filter_map(|line| {
+            let words: Vec<&str> = line.split(' ').collect();
+            match words.as_slice() {
+                ["move", amount, "from", src, "to", dest] => {
+                    Some((amount.parse().ok()?, src.parse().ok()?, dest.parse().ok()?))
+                }
+                _ => None,
+            }
+        })
+        .collect();
+
+    (stacks, moves)
+}
+
+fn reference_tops(stacks: &[Vec<char>]) -> String {
+    stacks.iter().filter_map(|stack| stack.last()).collect()
+}
+
+/// Naive version of `solve_1`, moving crates one at a time.
+pub fn reference_1(input: &str) -> SolveResult {
+    let (mut stacks, moves) = reference_parse(input);
+
+    for (amount, src, dest) in moves {
+        for _ in 0..amount {
+            match stacks[src - 1].pop() {
+                Some(c) => stacks[dest - 1].push(c),
+                None => return Err(SolveError::new("Not enough crates")),
+            }
+        }
+    }
+    Ok(reference_tops(&stacks))
+}
+
+/// Naive version of `solve_2`, moving crates one at a time through a
+/// temporary stack so they keep their order.
+pub fn reference_2(input: &str) -> SolveResult {
+    let (mut stacks, moves) = reference_parse(input);
+
+    for (amount, src, dest) in moves {
+        let mut held = Vec::new();
+        for _ in 0..amount {
+            match stacks[src - 1].pop() {
+                Some(c) => held.push(c),
+                None => return Err(SolveError::new("Not enough crates")),
+            }
+        }
+        while let Some(c) = held.pop() {
+            stacks[dest - 1].push(c);
+        }
+    }
+    Ok(reference_tops(&stacks))
+}
+
+// GENERATOR
+
+/// Generates a stack diagram followed by `size` moves, each taking no more
+/// crates than its stack holds.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let stack_count = rng.gen_range(3..=9);
+    let stacks: Vec<SupplyStack> = (1..=stack_count)
+        .map(|id| SupplyStack {
+            id,
+            stack: (0..rng.gen_range(1..=8))
+                .map(|_| Crate {
+                    label: char::from(rng.gen_range(b'A'..=b'Z')).to_string(),
+                })
+                .collect(),
+        })
+        .collect();
+    let mut heights: Vec<u32> = stacks.iter().map(|s| s.stack.len() as u32).collect();
+
+    let manifest = CargoManifest {
+        stacks: stacks.into_iter().map(|s| (s.id, s)).collect(),
+        instructions: vec![],
+    };
+    let mut lines = vec![manifest.render_stacks(), String::new()];
+
+    for _ in 0..size {
+        let non_empty: Vec<usize> = (0..heights.len()).filter(|&i| heights[i] > 0).collect();
+        let src = *non_empty.choose(rng).unwrap_or(&0);
+        let dest = (src + rng.gen_range(1..heights.len())) % heights.len();
+        let amount = rng.gen_range(1..=heights[src]);
+
+        heights[src] -= amount;
+        heights[dest] += amount;
+        lines.push(format!("move {} from {} to {}", amount, src + 1, dest + 1));
+    }
+
+    lines.join("\n") + "\n"
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/day_5/example.txt");
+
+    /// Crate labels for each stack, listed bottom to top.
+    type Stacks = Vec<Vec<char>>;
+    /// `(amount, src, dest)` for each move.
+    type Moves = Vec<(u32, u32, u32)>;
+
+    fn manifest() -> impl Strategy<Value = (Stacks, Moves)> {
+        prop::collection::vec(
+            prop::collection::vec(prop::char::range('A', 'Z'), 0..6),
+            1..=9,
+        )
+        .prop_flat_map(|stacks| {
+            let ids = 1..=stacks.len() as u32;
+            let moves = prop::collection::vec((1..50u32, ids.clone(), ids), 0..10);
+            (Just(stacks), moves)
+        })
+    }
 
-    const TEST_INPUT: &str = r#"
-    [D]    
-[N] [C]    
-[Z] [M] [P]
- 1   2   3 
-
-move 1 from 2 to 1
-move 3 from 1 to 3
-move 2 from 2 to 1
-move 1 from 1 to 2
-"#;
+    fn render(stacks: &[Vec<char>], moves: &[(u32, u32, u32)]) -> String {
+        let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+        let mut lines: Vec<String> = (0..height)
+            .rev()
+            .map(|level| {
+                stacks
+                    .iter()
+                    .map(|stack| {
+                        stack
+                            .get(level)
+                            .map_or(String::from("   "), |c| format!("[{}]", c))
+                    })
+                    .join(" ")
+            })
+            .collect();
+        lines.push((1..=stacks.len()).map(|id| format!(" {} ", id)).join(" "));
+        lines.push(String::new());
+        lines.extend(
+            moves
+                .iter()
+                .map(|(amount, src, dest)| format!("move {} from {} to {}", amount, src, dest)),
+        );
+        lines.join("\n")
+    }
 
-    #[test]
-    fn solve_1_correct() {
-        let result = solve_1(TEST_INPUT);
-        assert_eq!(result, "CMZ");
+    proptest! {
+        #[test]
+        fn parse_input_round_trips((stacks, moves) in manifest()) {
+            let input = render(&stacks, &moves);
+            let manifest = parse_input(&input, Parsing::Strict).unwrap();
+
+            let parsed: Vec<Vec<char>> = (1..=stacks.len() as u32)
+                .map(|id| {
+                    manifest.stacks[&id]
+                        .stack
+                        .iter()
+                        .map(|c| c.label.chars().next().unwrap())
+                        .collect()
+                })
+                .collect();
+            prop_assert_eq!(manifest.stacks.len(), stacks.len());
+            prop_assert_eq!(parsed, stacks);
+
+            let instructions: Vec<(u32, u32, u32)> = manifest
+                .instructions
+                .iter()
+                .map(|i| (i.amount, i.src, i.dest))
+                .collect();
+            prop_assert_eq!(instructions, moves);
+            let diagram = input.lines().take_while(|l| !l.is_empty()).join("\n");
+            prop_assert_eq!(manifest.render_stacks(), diagram);
+        }
     }
 
     #[test]
-    fn solve_2_correct() {
-        let result = solve_2(TEST_INPUT);
-        assert_eq!(result, "MCD");
+    fn visualize_draws_every_step() {
+        let frames = visualize(EXAMPLE);
+        assert_eq!(frames.len(), 5);
+        assert_eq!(
+            frames[1],
+            "move 1 from 2 to 1\n\n[D]        \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 "
+        );
     }
 }
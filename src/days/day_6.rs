@@ -1,19 +1,38 @@
 // PART 1
 
+use super::{Finding, ParseError, Parsing, SolveError, SolveResult};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
 use std::collections::HashSet;
 
-fn parse_input(input: &str) -> String {
-    return input.trim().to_string();
+/// Checks that the datastream is a single line of lowercase letters.
+fn check_datastream(input: &str) -> Result<(), ParseError> {
+    for (i, line) in input.trim_end().lines().enumerate() {
+        if i > 0 {
+            return Err(ParseError::new(i + 1, line, 0, "expected a single line"));
+        }
+        if let Some(index) = line.find(|c: char| !c.is_ascii_lowercase()) {
+            return Err(ParseError::new(1, line, index, "expected a letter, a-z"));
+        }
+    }
+    Ok(())
+}
+
+fn parse_input(input: &str, parsing: Parsing) -> Result<String, ParseError> {
+    if parsing == Parsing::Strict {
+        check_datastream(input)?;
+    }
+    Ok(input.trim().to_string())
 }
 
 fn find_marker_index(input: &str, marker_len: usize) -> Option<usize> {
     let mut found: HashSet<char> = HashSet::new();
 
-    for index in 0..(input.len() - marker_len) {
-        let s = &input[index..(index + marker_len)];
+    // Index by char rather than byte so non-ASCII input can't split a char.
+    let chars: Vec<char> = input.chars().collect();
 
-        for c in s.chars() {
-            found.insert(c);
+    for (index, window) in chars.windows(marker_len).enumerate() {
+        for c in window {
+            found.insert(*c);
         }
 
         if found.len() == marker_len {
@@ -26,35 +45,116 @@ fn find_marker_index(input: &str, marker_len: usize) -> Option<usize> {
     None
 }
 
-pub fn solve_1(input: &str) -> String {
-    let data = parse_input(input);
-    find_marker_index(&data, 4).map_or(String::from(""), |i| i.to_string())
+pub fn solve_1(input: &str, parsing: Parsing) -> SolveResult {
+    let data = parse_input(input, parsing)?;
+    find_marker_index(&data, 4)
+        .map(|i| i.to_string())
+        .ok_or_else(|| SolveError::new("No start-of-packet marker"))
 }
 
 // PART 2
 
-pub fn solve_2(input: &str) -> String {
-    let data = parse_input(input);
-    find_marker_index(&data, 14).map_or(String::from(""), |i| i.to_string())
+pub fn solve_2(input: &str, parsing: Parsing) -> SolveResult {
+    let data = parse_input(input, parsing)?;
+    find_marker_index(&data, 14)
+        .map(|i| i.to_string())
+        .ok_or_else(|| SolveError::new("No start-of-message marker"))
+}
+
+// LINT
+
+/// Reports a datastream that isn't a single line of lowercase letters or has
+/// no start-of-packet marker, and warns when it has no start-of-message
+/// marker, since part 1 can still be solved.
+pub fn lint(input: &str) -> Vec<Finding> {
+    if let Err(e) = check_datastream(input) {
+        return vec![Finding::error(e)];
+    }
+
+    let stream = input.trim();
+    if find_marker_index(stream, 4).is_none() {
+        let message = "no start-of-packet marker";
+        return vec![Finding::error(ParseError::new(1, stream, 0, message))];
+    }
+    if find_marker_index(stream, 14).is_none() {
+        let message = "no start-of-message marker, so part 2 has no answer";
+        return vec![Finding::warning(ParseError::new(1, stream, 0, message))];
+    }
+    vec![]
+}
+
+// REFERENCE
+
+/// Finds the end of the first window where no two characters are equal,
+/// comparing every pair.
+fn reference_marker(input: &str, marker_len: usize) -> SolveResult {
+    let data: Vec<char> = input.trim().chars().collect();
+
+    for end in marker_len..=data.len() {
+        let window = &data[end - marker_len..end];
+        let distinct = (0..marker_len).all(|i| (0..i).all(|j| window[i] != window[j]));
+        if distinct {
+            return Ok(end.to_string());
+        }
+    }
+    Err(SolveError::new("No marker"))
+}
+
+/// Naive version of `solve_1` for differential testing.
+pub fn reference_1(input: &str) -> SolveResult {
+    reference_marker(input, 4)
+}
+
+/// Naive version of `solve_2` for differential testing.
+pub fn reference_2(input: &str) -> SolveResult {
+    reference_marker(input, 14)
+}
+
+// GENERATOR
+
+/// Generates a datastream of `size` characters (at least 15) with a
+/// start-of-message marker placed before the end. The rest is drawn from six
+/// letters, which can hold start-of-packet markers but are too few to form a
+/// start-of-message marker on their own.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(15);
+    let mut stream: Vec<char> = (0..size)
+        .map(|_| char::from(rng.gen_range(b'a'..=b'f')))
+        .collect();
+
+    let mut marker: Vec<char> = ('a'..='z').collect();
+    marker.shuffle(rng);
+    let start = rng.gen_range(0..size - 14);
+    stream.splice(start..start + 14, marker.into_iter().take(14));
+
+    stream.into_iter().collect::<String>() + "\n"
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use proptest::prelude::*;
 
-    const TEST_INPUT: &str = r#"
-mjqjpqmgbljsphdztnvjfqwrcgsmlb
-"#;
-
-    #[test]
-    fn solve_1_correct() {
-        let result = solve_1(TEST_INPUT);
-        assert_eq!(result, "7");
+    fn is_marker(window: &str) -> bool {
+        window.chars().collect::<HashSet<char>>().len() == window.len()
     }
 
-    #[test]
-    fn solve_2_correct() {
-        let result = solve_2(TEST_INPUT);
-        assert_eq!(result, "19");
+    proptest! {
+        #[test]
+        fn marker_index_is_the_first_distinct_window(
+            stream in "[a-h]{0,40}",
+            marker_len in prop::sample::select(vec![4usize, 14]),
+        ) {
+            let first = (marker_len..=stream.len()).find(|&end| is_marker(&stream[end - marker_len..end]));
+
+            match find_marker_index(&stream, marker_len) {
+                Some(end) => {
+                    prop_assert!(end >= marker_len && end <= stream.len());
+                    prop_assert!(is_marker(&stream[end - marker_len..end]));
+                    prop_assert_eq!(Some(end), first);
+                }
+                None => prop_assert_eq!(first, None),
+            }
+        }
     }
 }
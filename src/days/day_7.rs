@@ -1,31 +1,13 @@
 // PART 1
 
-pub fn solve_1(_input: &str) -> String {
-    "".to_string()
-}
-
-// PART 2
+use super::{Parsing, SolveResult};
 
-pub fn solve_2(_input: &str) -> String {
-    "".to_string()
+pub fn solve_1(_input: &str, _parsing: Parsing) -> SolveResult {
+    Ok("".to_string())
 }
 
-#[cfg(test)]
-mod test {
-    //     use super::*;
-
-    //     const TEST_INPUT: &str = r#"
-    // "#;
-
-    // #[test]
-    // fn solve_1_correct() {
-    //     let result = solve_1(TEST_INPUT);
-    //     assert_eq!(result, "7");
-    // }
+// PART 2
 
-    // #[test]
-    // fn solve_2_correct() {
-    //     let result = solve_2(TEST_INPUT);
-    //     assert_eq!(result, "19");
-    // }
+pub fn solve_2(_input: &str, _parsing: Parsing) -> SolveResult {
+    Ok("".to_string())
 }
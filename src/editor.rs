@@ -0,0 +1,241 @@
+use crate::days::{catch_panic, Day, Status};
+
+use std::{fs, io, path::PathBuf};
+
+/// A multi-line text buffer for trying a day's solvers on a custom input.
+pub struct Editor {
+    /// Index into the day table's items of the day being edited.
+    pub item: usize,
+    lines: Vec<String>,
+    row: usize,
+    /// Cursor position in characters, not bytes.
+    column: usize,
+    /// First line and column shown, kept so the cursor stays in view.
+    pub top: usize,
+    pub left: usize,
+    /// Results of the last run, one per part.
+    pub results: [Status; 2],
+}
+
+fn byte_index(line: &str, column: usize) -> usize {
+    line.char_indices()
+        .nth(column)
+        .map_or(line.len(), |(i, _)| i)
+}
+
+impl Editor {
+    pub fn new(item: usize) -> Self {
+        Editor {
+            item,
+            lines: vec![String::new()],
+            row: 0,
+            column: 0,
+            top: 0,
+            left: 0,
+            results: [Status::Unsolved, Status::Unsolved],
+        }
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    pub fn cursor(&self) -> (usize, usize) {
+        (self.row, self.column)
+    }
+
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    fn line_len(&self) -> usize {
+        self.lines[self.row].chars().count()
+    }
+
+    pub fn insert(&mut self, c: char) {
+        if c == '\n' {
+            return self.newline();
+        }
+
+        let line = &mut self.lines[self.row];
+        line.insert(byte_index(line, self.column), c);
+        self.column += 1;
+    }
+
+    /// Inserts pasted text at the cursor, normalizing Windows line endings.
+    pub fn paste(&mut self, text: &str) {
+        for c in text.replace("\r\n", "\n").chars() {
+            self.insert(if c == '\r' { '\n' } else { c });
+        }
+    }
+
+    pub fn newline(&mut self) {
+        let line = &mut self.lines[self.row];
+        let rest = line.split_off(byte_index(line, self.column));
+        self.row += 1;
+        self.column = 0;
+        self.lines.insert(self.row, rest);
+    }
+
+    pub fn backspace(&mut self) {
+        if self.column > 0 {
+            self.column -= 1;
+            let line = &mut self.lines[self.row];
+            line.remove(byte_index(line, self.column));
+        } else if self.row > 0 {
+            let line = self.lines.remove(self.row);
+            self.row -= 1;
+            self.column = self.line_len();
+            self.lines[self.row].push_str(&line);
+        }
+    }
+
+    pub fn delete(&mut self) {
+        if self.column < self.line_len() {
+            let line = &mut self.lines[self.row];
+            line.remove(byte_index(line, self.column));
+        } else if self.row + 1 < self.lines.len() {
+            let next = self.lines.remove(self.row + 1);
+            self.lines[self.row].push_str(&next);
+        }
+    }
+
+    pub fn left(&mut self) {
+        if self.column > 0 {
+            self.column -= 1;
+        } else if self.row > 0 {
+            self.row -= 1;
+            self.column = self.line_len();
+        }
+    }
+
+    pub fn right(&mut self) {
+        if self.column < self.line_len() {
+            self.column += 1;
+        } else if self.row + 1 < self.lines.len() {
+            self.row += 1;
+            self.column = 0;
+        }
+    }
+
+    pub fn up(&mut self) {
+        if self.row > 0 {
+            self.row -= 1;
+            self.column = self.column.min(self.line_len());
+        }
+    }
+
+    pub fn down(&mut self) {
+        if self.row + 1 < self.lines.len() {
+            self.row += 1;
+            self.column = self.column.min(self.line_len());
+        }
+    }
+
+    pub fn home(&mut self) {
+        self.column = 0;
+    }
+
+    pub fn end(&mut self) {
+        self.column = self.line_len();
+    }
+
+    /// Scrolls just far enough for the cursor to fit in a `width` by
+    /// `height` view.
+    pub fn scroll_to_cursor(&mut self, width: usize, height: usize) {
+        if self.row < self.top {
+            self.top = self.row;
+        } else if height > 0 && self.row >= self.top + height {
+            self.top = self.row + 1 - height;
+        }
+
+        if self.column < self.left {
+            self.left = self.column;
+        } else if width > 0 && self.column >= self.left + width {
+            self.left = self.column + 1 - width;
+        }
+    }
+
+    /// Runs both of the day's solvers on the buffer. A solver that rejects or
+    /// panics on the input is reported as failed rather than taking down the
+    /// UI.
+    pub fn run(&mut self, day: &Day) {
+        let input = self.text();
+
+        for (part, result) in (1..=2).zip(self.results.iter_mut()) {
+            let problem = day.part(part);
+            *result = match catch_panic(|| problem.solve_input(&input)) {
+                Ok(Some(Ok(solution))) => Status::Solved(solution),
+                Ok(Some(Err(e))) => Status::Failed(e.to_string()),
+                Ok(None) => Status::Unsolved,
+                Err(message) => Status::Failed(message),
+            };
+        }
+    }
+
+    /// Saves the buffer as `inputs/day_N/custom.txt`, returning its path.
+    pub fn save(&self, day: &Day) -> io::Result<PathBuf> {
+        let dir = day.input_dir();
+        fs::create_dir_all(&dir)?;
+
+        let path = dir.join("custom.txt");
+        let mut text = self.text();
+        if !text.ends_with('\n') {
+            text.push('\n');
+        }
+        fs::write(&path, text)?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::days::init_days;
+
+    #[test]
+    fn edits_across_lines() {
+        let mut editor = Editor::new(0);
+        editor.paste("ab\r\ncd");
+        assert_eq!(editor.lines(), ["ab", "cd"]);
+        assert_eq!(editor.cursor(), (1, 2));
+
+        editor.home();
+        editor.backspace();
+        assert_eq!(editor.text(), "abcd");
+        assert_eq!(editor.cursor(), (0, 2));
+
+        editor.insert('é');
+        editor.newline();
+        editor.up();
+        editor.end();
+        editor.delete();
+        assert_eq!(editor.text(), "abécd");
+
+        editor.left();
+        editor.insert('x');
+        assert_eq!(editor.text(), "abxécd");
+    }
+
+    #[test]
+    fn runs_solvers_and_reports_errors() {
+        let days = init_days();
+        let mut editor = Editor::new(0);
+        editor.paste("1000\n2000\n\n4000\n");
+        editor.run(&days[0]);
+        assert_eq!(
+            editor.results.each_ref().map(|r| match r {
+                Status::Solved(solution) => solution.answer.clone(),
+                _ => String::new(),
+            }),
+            [String::from("4000"), String::from("7000")]
+        );
+
+        // Both halves hold a '!', which isn't an item, and splitting the sack
+        // in half by bytes would land inside the 'é'.
+        let mut editor = Editor::new(2);
+        editor.paste("é!a!\n");
+        editor.run(&days[2]);
+        assert!(matches!(editor.results[0], Status::Failed(_)));
+    }
+}
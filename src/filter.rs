@@ -0,0 +1,128 @@
+use crate::days::Day;
+
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuickFilter {
+    All,
+    Unsolved,
+    Errored,
+    Slow,
+}
+
+impl QuickFilter {
+    pub fn next(self) -> Self {
+        match self {
+            QuickFilter::All => QuickFilter::Unsolved,
+            QuickFilter::Unsolved => QuickFilter::Errored,
+            QuickFilter::Errored => QuickFilter::Slow,
+            QuickFilter::Slow => QuickFilter::All,
+        }
+    }
+
+    fn matches(&self, day: &Day, slow: Duration) -> bool {
+        let parts = day.parts();
+
+        match self {
+            QuickFilter::All => true,
+            QuickFilter::Unsolved => parts
+                .iter()
+                .any(|p| p.is_implemented() && p.solution().is_none()),
+            QuickFilter::Errored => parts.iter().any(|p| p.error().is_some()),
+            QuickFilter::Slow => parts.iter().any(|p| p.elapsed().is_some_and(|e| e > slow)),
+        }
+    }
+}
+
+pub struct Filter {
+    pub query: String,
+    pub quick: QuickFilter,
+    /// Whether keys are currently being typed into the query.
+    pub editing: bool,
+    pub slow: Duration,
+}
+
+impl Filter {
+    pub fn new(slow: Duration) -> Self {
+        Filter {
+            query: String::new(),
+            quick: QuickFilter::All,
+            editing: false,
+            slow,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        !self.query.is_empty() || self.quick != QuickFilter::All
+    }
+
+    fn matches_query(&self, day: &Day) -> bool {
+        let query = self.query.trim().to_lowercase();
+
+        query.is_empty()
+            || query.parse::<u8>() == Ok(day.advent_day())
+            || day.title().to_lowercase().contains(&query)
+    }
+
+    pub fn matches(&self, day: &Day) -> bool {
+        self.matches_query(day) && self.quick.matches(day, self.slow)
+    }
+
+    pub fn describe(&self) -> String {
+        let quick = match self.quick {
+            QuickFilter::All => None,
+            QuickFilter::Unsolved => Some(String::from("unsolved")),
+            QuickFilter::Errored => Some(String::from("errored")),
+            QuickFilter::Slow => Some(format!("slower than {:?}", self.slow)),
+        };
+
+        let query = Some(self.query.trim())
+            .filter(|q| !q.is_empty())
+            .map(|q| format!("/{}", q));
+
+        query
+            .into_iter()
+            .chain(quick)
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::days::init_days;
+
+    fn matching(filter: &Filter) -> Vec<u8> {
+        init_days()
+            .iter()
+            .filter(|day| filter.matches(day))
+            .map(|day| day.advent_day())
+            .collect()
+    }
+
+    #[test]
+    fn query_matches_title_or_day_number() {
+        let mut filter = Filter::new(Duration::from_millis(100));
+
+        filter.query = String::from("CAMP");
+        assert_eq!(matching(&filter), vec![4]);
+
+        filter.query = String::from("6");
+        assert_eq!(matching(&filter), vec![6]);
+
+        filter.query = String::from("ing");
+        assert_eq!(matching(&filter), vec![1, 6]);
+    }
+
+    #[test]
+    fn quick_filters_use_problem_state() {
+        let mut filter = Filter::new(Duration::from_millis(100));
+
+        filter.quick = QuickFilter::Unsolved;
+        assert_eq!(matching(&filter), vec![1, 2, 3, 4, 5, 6]);
+
+        filter.quick = QuickFilter::Errored;
+        assert!(matching(&filter).is_empty());
+    }
+}
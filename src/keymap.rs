@@ -0,0 +1,396 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::Deserialize;
+use std::{collections::HashMap, convert::TryFrom, fmt};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    Quit,
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+    Top,
+    Bottom,
+    ToggleDetail,
+    ScrollLeft,
+    ScrollRight,
+    NextScreen,
+    PreviousScreen,
+    ShowDays,
+    ShowBenchmarks,
+    ShowLogs,
+    ShowVisualizations,
+    Solve,
+    SolvePart1,
+    SolvePart2,
+    SolveAll,
+    Resolve,
+    ResolveAll,
+    CopyPart1,
+    CopyPart2,
+    OpenPuzzle,
+    OpenInput,
+    OpenInputFile,
+    EditInput,
+    Filter,
+    CycleFilter,
+    CycleSort,
+    Help,
+    Cancel,
+}
+
+impl Action {
+    /// Every action, in the order they are listed in the help overlay.
+    pub const ALL: [Action; 33] = [
+        Action::Help,
+        Action::Quit,
+        Action::Cancel,
+        Action::Up,
+        Action::Down,
+        Action::PageUp,
+        Action::PageDown,
+        Action::Top,
+        Action::Bottom,
+        Action::ToggleDetail,
+        Action::ScrollLeft,
+        Action::ScrollRight,
+        Action::NextScreen,
+        Action::PreviousScreen,
+        Action::ShowDays,
+        Action::ShowBenchmarks,
+        Action::ShowLogs,
+        Action::ShowVisualizations,
+        Action::Filter,
+        Action::CycleFilter,
+        Action::CycleSort,
+        Action::Solve,
+        Action::SolvePart1,
+        Action::SolvePart2,
+        Action::SolveAll,
+        Action::Resolve,
+        Action::ResolveAll,
+        Action::CopyPart1,
+        Action::CopyPart2,
+        Action::OpenPuzzle,
+        Action::OpenInput,
+        Action::OpenInputFile,
+        Action::EditInput,
+    ];
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Action::Quit => "Quit",
+            Action::Up => "Previous day, log line or frame",
+            Action::Down => "Next day, log line or frame",
+            Action::PageUp => "Page up",
+            Action::PageDown => "Page down",
+            Action::Top => "Jump to first",
+            Action::Bottom => "Jump to last",
+            Action::ToggleDetail => "Toggle detail pane",
+            Action::ScrollLeft => "Scroll answers left",
+            Action::ScrollRight => "Scroll answers right",
+            Action::NextScreen => "Next screen",
+            Action::PreviousScreen => "Previous screen",
+            Action::ShowDays => "Show days",
+            Action::ShowBenchmarks => "Show runtime benchmarks",
+            Action::ShowLogs => "Show logs",
+            Action::ShowVisualizations => "Show visualization of selected day",
+            Action::Solve => "Solve selected day",
+            Action::SolvePart1 => "Solve Part 1 of selected day",
+            Action::SolvePart2 => "Solve Part 2 of selected day",
+            Action::SolveAll => "Solve all days in parallel",
+            Action::Resolve => "Clear and re-solve selected day",
+            Action::ResolveAll => "Clear and re-solve all days",
+            Action::CopyPart1 => "Copy Part 1 answer",
+            Action::CopyPart2 => "Copy Part 2 answer",
+            Action::OpenPuzzle => "Open puzzle page",
+            Action::OpenInput => "Open puzzle input page",
+            Action::OpenInputFile => "Open local input file",
+            Action::EditInput => "Try solvers on a custom input",
+            Action::Filter => "Filter days by title or number",
+            Action::CycleFilter => "Cycle unsolved/errored/slow filter",
+            Action::CycleSort => "Cycle the table sort column and direction",
+            Action::Help => "Toggle this help",
+            Action::Cancel => "Close popup, screen or pane, then clear filter",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+#[serde(try_from = "String")]
+pub struct KeyBinding {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        // Shift is already part of the character for char keys ('S' vs 's'),
+        // and terminals disagree on whether they report it.
+        let modifiers = match code {
+            KeyCode::Char(_) => modifiers - KeyModifiers::SHIFT,
+            _ => modifiers,
+        };
+
+        Self { code, modifiers }
+    }
+}
+
+impl From<KeyEvent> for KeyBinding {
+    fn from(event: KeyEvent) -> Self {
+        KeyBinding::new(event.code, event.modifiers)
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "ctrl-")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "alt-")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "shift-")?;
+        }
+
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "space"),
+            KeyCode::Char('-') => write!(f, "minus"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::Up => write!(f, "up"),
+            KeyCode::Down => write!(f, "down"),
+            KeyCode::Left => write!(f, "left"),
+            KeyCode::Right => write!(f, "right"),
+            KeyCode::PageUp => write!(f, "pageup"),
+            KeyCode::PageDown => write!(f, "pagedown"),
+            KeyCode::Home => write!(f, "home"),
+            KeyCode::End => write!(f, "end"),
+            KeyCode::Enter => write!(f, "enter"),
+            KeyCode::Esc => write!(f, "esc"),
+            KeyCode::Tab => write!(f, "tab"),
+            KeyCode::BackTab => write!(f, "backtab"),
+            KeyCode::Backspace => write!(f, "backspace"),
+            KeyCode::Delete => write!(f, "delete"),
+            KeyCode::F(n) => write!(f, "f{}", n),
+            code => write!(f, "{:?}", code),
+        }
+    }
+}
+
+fn parse_code(name: &str) -> Option<KeyCode> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(KeyCode::Char(c));
+    }
+
+    let code = match name.to_lowercase().as_str() {
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "enter" => KeyCode::Enter,
+        "esc" => KeyCode::Esc,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "backspace" => KeyCode::Backspace,
+        "delete" => KeyCode::Delete,
+        "space" => KeyCode::Char(' '),
+        "minus" => KeyCode::Char('-'),
+        f if f.starts_with('f') => KeyCode::F(f[1..].parse().ok()?),
+        _ => return None,
+    };
+
+    Some(code)
+}
+
+impl TryFrom<String> for KeyBinding {
+    type Error = String;
+
+    /// Parses keys like `j`, `G`, `pagedown` or `ctrl-c`.
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let mut parts: Vec<&str> = value.split('-').collect();
+        let name = parts.pop().filter(|n| !n.is_empty());
+        let name = name.ok_or_else(|| format!("invalid key `{}`", value))?;
+
+        let mut modifiers = KeyModifiers::NONE;
+        for modifier in parts {
+            modifiers |= match modifier.to_lowercase().as_str() {
+                "ctrl" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return Err(format!("invalid modifier `{}` in `{}`", modifier, value)),
+            };
+        }
+
+        let code = parse_code(name).ok_or_else(|| format!("invalid key `{}`", value))?;
+
+        Ok(KeyBinding::new(code, modifiers))
+    }
+}
+
+const DEFAULT_BINDINGS: &[(&str, Action)] = &[
+    ("q", Action::Quit),
+    ("ctrl-c", Action::Quit),
+    ("up", Action::Up),
+    ("k", Action::Up),
+    ("down", Action::Down),
+    ("j", Action::Down),
+    ("pageup", Action::PageUp),
+    ("pagedown", Action::PageDown),
+    ("home", Action::Top),
+    ("g", Action::Top),
+    ("end", Action::Bottom),
+    ("G", Action::Bottom),
+    ("enter", Action::ToggleDetail),
+    ("left", Action::ScrollLeft),
+    ("h", Action::ScrollLeft),
+    ("right", Action::ScrollRight),
+    ("l", Action::ScrollRight),
+    ("tab", Action::NextScreen),
+    ("backtab", Action::PreviousScreen),
+    ("1", Action::ShowDays),
+    ("2", Action::ShowBenchmarks),
+    ("3", Action::ShowLogs),
+    ("4", Action::ShowVisualizations),
+    ("s", Action::Solve),
+    ("p", Action::SolvePart1),
+    ("P", Action::SolvePart2),
+    ("S", Action::SolveAll),
+    ("r", Action::Resolve),
+    ("R", Action::ResolveAll),
+    ("y", Action::CopyPart1),
+    ("Y", Action::CopyPart2),
+    ("o", Action::OpenPuzzle),
+    ("i", Action::OpenInput),
+    ("I", Action::OpenInputFile),
+    ("e", Action::EditInput),
+    ("/", Action::Filter),
+    ("f", Action::CycleFilter),
+    ("t", Action::CycleSort),
+    ("?", Action::Help),
+    ("esc", Action::Cancel),
+];
+
+pub struct Keymap {
+    bindings: HashMap<KeyBinding, Action>,
+}
+
+impl Keymap {
+    /// Builds the default keymap, replacing the keys of every action that
+    /// appears in `overrides`.
+    pub fn new(overrides: &HashMap<Action, Vec<KeyBinding>>) -> Self {
+        let mut bindings: HashMap<KeyBinding, Action> = DEFAULT_BINDINGS
+            .iter()
+            .filter(|(_, action)| !overrides.contains_key(action))
+            .map(|(key, action)| {
+                let binding = KeyBinding::try_from(key.to_string()).expect("Invalid default key");
+                (binding, *action)
+            })
+            .collect();
+
+        for (action, keys) in overrides {
+            for key in keys {
+                bindings.insert(*key, *action);
+            }
+        }
+
+        Keymap { bindings }
+    }
+
+    pub fn action(&self, event: KeyEvent) -> Option<Action> {
+        self.bindings.get(&KeyBinding::from(event)).copied()
+    }
+
+    /// All keys bound to `action`, formatted and sorted for display.
+    pub fn keys_for(&self, action: Action) -> Vec<String> {
+        let mut keys: Vec<String> = self
+            .bindings
+            .iter()
+            .filter(|(_, a)| **a == action)
+            .map(|(key, _)| key.to_string())
+            .collect();
+
+        // Single characters first, so the mnemonic key leads.
+        keys.sort_by_key(|k| (k.chars().count() > 1, k.clone()));
+        keys
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn key(value: &str) -> KeyBinding {
+        KeyBinding::try_from(value.to_string()).unwrap()
+    }
+
+    #[test]
+    fn parses_keys() {
+        assert_eq!(
+            key("j"),
+            KeyBinding::new(KeyCode::Char('j'), KeyModifiers::NONE)
+        );
+        assert_eq!(
+            key("G"),
+            KeyBinding::new(KeyCode::Char('G'), KeyModifiers::NONE)
+        );
+        assert_eq!(
+            key("minus"),
+            KeyBinding::new(KeyCode::Char('-'), KeyModifiers::NONE)
+        );
+        assert_eq!(
+            key("PageDown"),
+            KeyBinding::new(KeyCode::PageDown, KeyModifiers::NONE)
+        );
+        assert_eq!(
+            key("ctrl-c"),
+            KeyBinding::new(KeyCode::Char('c'), KeyModifiers::CONTROL)
+        );
+        assert!(KeyBinding::try_from(String::from("hyper-x")).is_err());
+        assert!(KeyBinding::try_from(String::from("nope")).is_err());
+    }
+
+    #[test]
+    fn overrides_replace_default_keys() {
+        let overrides = HashMap::from([(Action::Solve, vec![key("x")])]);
+        let keymap = Keymap::new(&overrides);
+
+        let event = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+        assert_eq!(keymap.action(event('x')), Some(Action::Solve));
+        assert_eq!(keymap.action(event('s')), None);
+        assert_eq!(keymap.action(event('j')), Some(Action::Down));
+    }
+
+    #[test]
+    fn every_default_key_round_trips() {
+        for (value, _) in DEFAULT_BINDINGS {
+            let binding = key(value);
+            assert_eq!(key(&binding.to_string()), binding);
+        }
+    }
+
+    #[test]
+    fn every_action_is_listed_and_bound() {
+        let keymap = Keymap::new(&HashMap::new());
+
+        for (_, action) in DEFAULT_BINDINGS {
+            assert!(Action::ALL.contains(action));
+        }
+        for action in Action::ALL {
+            assert!(!keymap.keys_for(action).is_empty(), "{:?}", action);
+        }
+    }
+
+    #[test]
+    fn shifted_chars_match_without_shift() {
+        let keymap = Keymap::new(&HashMap::new());
+        let event = KeyEvent::new(KeyCode::Char('G'), KeyModifiers::SHIFT);
+        assert_eq!(keymap.action(event), Some(Action::Bottom));
+    }
+}
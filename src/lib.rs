@@ -0,0 +1,15 @@
+mod app;
+mod clipboard;
+pub mod commands;
+pub mod config;
+pub mod days;
+mod editor;
+mod filter;
+mod keymap;
+mod ocr;
+mod opener;
+mod sort;
+mod summary;
+pub mod term;
+mod theme;
+mod ui;
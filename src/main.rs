@@ -1,11 +1,8 @@
-mod app;
-mod days;
-mod term;
-mod ui;
-
-use crate::term::run;
+use aoc2022::commands::Command;
+use aoc2022::config::Config;
+use aoc2022::term::run;
 use argh::FromArgs;
-use std::{error::Error, time::Duration};
+use std::{error::Error, path::PathBuf, time::Duration};
 
 /// AoC UI
 #[derive(Debug, FromArgs)]
@@ -16,11 +13,31 @@ struct Cli {
     /// whether unicode symbols are used to improve the overall look of the app
     #[argh(option, default = "true")]
     enhanced_graphics: bool,
+    /// path to the TOML config file.
+    #[argh(option, default = "PathBuf::from(\"aoc2022.toml\")")]
+    config: PathBuf,
+    /// number of threads used to solve every day, overriding the config.
+    #[argh(option)]
+    threads: Option<usize>,
+    /// fail on the first malformed input line instead of skipping it.
+    #[argh(switch)]
+    strict: bool,
+    #[argh(subcommand)]
+    command: Option<Command>,
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
     let cli: Cli = argh::from_env();
+    if let Some(command) = cli.command {
+        return command.execute();
+    }
+
     let tick_rate = Duration::from_millis(cli.tick_rate);
-    run(tick_rate, cli.enhanced_graphics)?;
+    let mut config = Config::load(&cli.config)?;
+    if cli.threads.is_some() {
+        config.threads = cli.threads;
+    }
+    config.strict |= cli.strict;
+    run(tick_rate, cli.enhanced_graphics, config)?;
     Ok(())
 }
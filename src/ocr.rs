@@ -0,0 +1,236 @@
+//! Reads the capital letters some puzzles draw as their answer, e.g. a CRT
+//! screen of `#` and `.` characters, back into a plain string.
+
+/// A font of fixed-size letters placed `stride` columns apart.
+struct Font {
+    width: usize,
+    height: usize,
+    stride: usize,
+    glyphs: &'static [(char, &'static [&'static str])],
+}
+
+const SMALL: Font = Font {
+    width: 4,
+    height: 6,
+    stride: 5,
+    glyphs: &[
+        ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+        ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+        ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+        ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+        ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+        ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+        ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+        ('I', &[".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+        ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+        ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+        ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+        ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+        ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+        ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+        ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+    ],
+};
+
+const LARGE: Font = Font {
+    width: 6,
+    height: 10,
+    stride: 8,
+    glyphs: &[
+        (
+            'A',
+            &[
+                "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'B',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+                "#....#", "#####.",
+            ],
+        ),
+        (
+            'C',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#....#", ".####.",
+            ],
+        ),
+        (
+            'E',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'F',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'G',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+                "#...##", ".###.#",
+            ],
+        ),
+        (
+            'H',
+            &[
+                "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'J',
+            &[
+                "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+                "#...#.", ".###..",
+            ],
+        ),
+        (
+            'K',
+            &[
+                "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+                "#...#.", "#....#",
+            ],
+        ),
+        (
+            'L',
+            &[
+                "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'N',
+            &[
+                "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+                "#...##", "#....#",
+            ],
+        ),
+        (
+            'P',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'R',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'X',
+            &[
+                "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'Z',
+            &[
+                "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+    ],
+};
+
+fn is_lit(c: char) -> bool {
+    matches!(c, '#' | '█')
+}
+
+impl Font {
+    fn recognize(&self, rows: &[Vec<bool>]) -> Option<String> {
+        let columns = rows.iter().map(|row| row.len()).max()?;
+        let letters = (columns + self.stride - self.width) / self.stride;
+        let pixel = |x: usize, y: usize| rows[y].get(x).copied().unwrap_or(false);
+
+        (0..letters)
+            .map(|letter| {
+                let left = letter * self.stride;
+                self.glyphs
+                    .iter()
+                    .find(|(_, glyph)| {
+                        glyph.iter().enumerate().all(|(y, line)| {
+                            line.chars()
+                                .enumerate()
+                                .all(|(x, c)| is_lit(c) == pixel(left + x, y))
+                        })
+                    })
+                    .map(|(letter, _)| *letter)
+            })
+            .collect()
+    }
+}
+
+/// Reads letters drawn in either the 4x6 or the 6x10 font, with `#` (or `█`)
+/// for lit pixels. Returns `None` when any letter is not recognized.
+pub fn recognize(art: &str) -> Option<String> {
+    let rows: Vec<Vec<bool>> = art
+        .lines()
+        .map(str::trim_end)
+        .skip_while(|line| line.is_empty())
+        .map(|line| line.chars().map(is_lit).collect())
+        .collect();
+    let height = rows.iter().rposition(|row| !row.is_empty())? + 1;
+    let rows = &rows[..height];
+
+    [SMALL, LARGE]
+        .iter()
+        .find(|font| font.height == height)?
+        .recognize(rows)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SCREEN: &str = "\
+####.#..#.####.####.####.#..#..##..####.
+#....#..#....#.#.......#.#..#.#..#....#.
+###..####...#..###....#..####.#......#..
+#....#..#..#...#.....#...#..#.#.....#...
+#....#..#.#....#....#....#..#.#..#.#....
+####.#..#.####.#....####.#..#..##..####.";
+
+    #[test]
+    fn reads_small_letters() {
+        assert_eq!(recognize(SCREEN).as_deref(), Some("EHZFZHCZ"));
+        assert_eq!(
+            recognize(&SCREEN.replace('#', "█").replace('.', " ")).as_deref(),
+            Some("EHZFZHCZ")
+        );
+    }
+
+    #[test]
+    fn reads_large_letters() {
+        let art: Vec<String> = (0..LARGE.height)
+            .map(|y| {
+                "NXZ"
+                    .chars()
+                    .map(|c| LARGE.glyphs.iter().find(|(l, _)| *l == c).unwrap().1[y])
+                    .collect::<Vec<&str>>()
+                    .join("..")
+            })
+            .collect();
+        assert_eq!(recognize(&art.join("\n")).as_deref(), Some("NXZ"));
+    }
+
+    #[test]
+    fn rejects_unknown_shapes() {
+        assert_eq!(recognize("#.#\n.#.\n#.#"), None);
+        assert_eq!(recognize(&SCREEN.replacen("####", "#.##", 1)), None);
+    }
+}
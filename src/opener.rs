@@ -0,0 +1,95 @@
+use std::{
+    env, io,
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+const FALLBACK_OPENERS: [&str; 2] = ["xdg-open", "open"];
+
+/// How long an opener has to fail before it is taken to have worked. Browsers
+/// started directly keep running until they are closed.
+const STARTUP_GRACE: Duration = Duration::from_millis(500);
+
+fn spawn(command: &str, target: &str) -> io::Result<()> {
+    let mut parts = command.split_whitespace();
+    let program = parts
+        .next()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "empty opener command"))?;
+
+    // Like `$BROWSER`, a `%s` marks where the target goes; otherwise it is appended.
+    let mut args: Vec<String> = parts.map(|p| p.replace("%s", target)).collect();
+    if !command.contains("%s") {
+        args.push(target.to_string());
+    }
+
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+
+    let started = Instant::now();
+    while started.elapsed() < STARTUP_GRACE {
+        if let Some(status) = child.try_wait()? {
+            if status.success() {
+                return Ok(());
+            }
+            return Err(io::Error::other(format!(
+                "`{}` exited with {}",
+                command, status
+            )));
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    // Still running, so reap it in the background once it exits.
+    thread::spawn(move || child.wait());
+    Ok(())
+}
+
+/// Candidate commands in the order they are tried: the configured opener, each
+/// entry of `$BROWSER`, then the platform defaults.
+fn candidates(configured: Option<&str>) -> Vec<String> {
+    let mut commands: Vec<String> = configured.into_iter().map(String::from).collect();
+
+    if let Ok(browser) = env::var("BROWSER") {
+        commands.extend(
+            browser
+                .split(':')
+                .filter(|b| !b.is_empty())
+                .map(String::from),
+        );
+    }
+
+    commands.extend(FALLBACK_OPENERS.iter().map(|o| o.to_string()));
+    commands
+}
+
+/// Opens a URL or file path with the first opener that starts and doesn't
+/// fail.
+pub fn open(configured: Option<&str>, target: &str) -> io::Result<()> {
+    let mut last_error = None;
+
+    for command in candidates(configured) {
+        match spawn(&command, target) {
+            Ok(()) => return Ok(()),
+            Err(e) => last_error = Some(e),
+        }
+    }
+
+    Err(last_error.unwrap_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no opener found")))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn failing_openers_are_errors() {
+        assert!(spawn("true", "https://adventofcode.com").is_ok());
+        assert!(spawn("false", "https://adventofcode.com").is_err());
+        assert!(spawn("sleep %s", "1").is_ok());
+    }
+}
@@ -0,0 +1,141 @@
+use crate::days::Day;
+
+use std::{cmp::Ordering, time::Duration};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortKey {
+    Day,
+    Title,
+    Part1Time,
+    Part2Time,
+    Stars,
+    Errors,
+}
+
+impl SortKey {
+    pub fn next(self) -> Self {
+        match self {
+            SortKey::Day => SortKey::Title,
+            SortKey::Title => SortKey::Part1Time,
+            SortKey::Part1Time => SortKey::Part2Time,
+            SortKey::Part2Time => SortKey::Stars,
+            SortKey::Stars => SortKey::Errors,
+            SortKey::Errors => SortKey::Day,
+        }
+    }
+
+    /// The day table column the sort is shown on, and the header it gets.
+    pub fn column(self) -> (usize, &'static str) {
+        match self {
+            SortKey::Day => (0, "Day"),
+            SortKey::Title => (0, "Title"),
+            SortKey::Errors => (0, "Errors"),
+            SortKey::Part1Time => (1, "Part 1 time"),
+            SortKey::Part2Time => (2, "Part 2 time"),
+            SortKey::Stars => (3, "Stars"),
+        }
+    }
+}
+
+fn elapsed(day: &Day, part: u8) -> Duration {
+    // Parts without a runtime sort after the slowest one.
+    day.part(part).elapsed().unwrap_or(Duration::MAX)
+}
+
+fn errors(day: &Day) -> usize {
+    day.parts().iter().filter(|p| p.error().is_some()).count()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sort {
+    pub key: SortKey,
+    pub descending: bool,
+}
+
+impl Default for Sort {
+    fn default() -> Self {
+        Sort {
+            key: SortKey::Day,
+            descending: false,
+        }
+    }
+}
+
+impl Sort {
+    /// Moves to the next column, then to the same columns in descending
+    /// order, then back to sorting by day.
+    pub fn next(self) -> Self {
+        match (self.key.next(), self.descending) {
+            (SortKey::Day, descending) => Sort {
+                key: SortKey::Day,
+                descending: !descending,
+            },
+            (key, descending) => Sort { key, descending },
+        }
+    }
+
+    /// Orders days by the sort key, falling back to the day number for ties.
+    pub fn compare(&self, a: &Day, b: &Day) -> Ordering {
+        let ordering = match self.key {
+            SortKey::Day => Ordering::Equal,
+            SortKey::Title => a.title().to_lowercase().cmp(&b.title().to_lowercase()),
+            SortKey::Part1Time => elapsed(a, 1).cmp(&elapsed(b, 1)),
+            SortKey::Part2Time => elapsed(a, 2).cmp(&elapsed(b, 2)),
+            SortKey::Stars => a.star_count().cmp(&b.star_count()),
+            SortKey::Errors => errors(a).cmp(&errors(b)),
+        }
+        .then_with(|| a.advent_day().cmp(&b.advent_day()));
+
+        if self.descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::days::init_days;
+
+    fn sorted_days(sort: Sort) -> Vec<u8> {
+        let mut days = init_days();
+        days.sort_by(|a, b| sort.compare(a, b));
+        days.iter().map(|day| day.advent_day()).collect()
+    }
+
+    #[test]
+    fn sorts_by_title_and_stars() {
+        let by_title = Sort {
+            key: SortKey::Title,
+            descending: false,
+        };
+        assert_eq!(sorted_days(by_title), vec![1, 4, 2, 3, 5, 7, 6]);
+
+        let by_stars = Sort {
+            key: SortKey::Stars,
+            descending: true,
+        };
+        assert_eq!(sorted_days(by_stars), vec![6, 5, 4, 3, 2, 1, 7]);
+    }
+
+    #[test]
+    fn cycles_through_every_key_in_both_directions() {
+        let mut sort = Sort::default();
+        for _ in 0..6 {
+            sort = sort.next();
+        }
+        assert_eq!(
+            sort,
+            Sort {
+                key: SortKey::Day,
+                descending: true
+            }
+        );
+        for _ in 0..6 {
+            sort = sort.next();
+        }
+        assert_eq!(sort, Sort::default());
+    }
+}
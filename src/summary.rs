@@ -0,0 +1,71 @@
+use crate::days::Day;
+
+use std::time::Duration;
+
+pub const TOTAL_STARS: usize = 50;
+
+/// Totals across every day, for the footer under the day table.
+pub struct Summary {
+    pub stars: usize,
+    pub total_time: Duration,
+    /// Slowest solved part as `(day, part, elapsed)`.
+    pub slowest: Option<(u8, u8, Duration)>,
+    pub errored: usize,
+    pub unimplemented: usize,
+}
+
+impl Summary {
+    pub fn new(days: &[Day]) -> Self {
+        let mut summary = Summary {
+            stars: 0,
+            total_time: Duration::ZERO,
+            slowest: None,
+            errored: 0,
+            unimplemented: 0,
+        };
+
+        for day in days {
+            summary.stars += day.star_count();
+
+            if day.parts().iter().any(|p| p.error().is_some()) {
+                summary.errored += 1;
+            }
+            if day.parts().iter().any(|p| !p.is_implemented()) {
+                summary.unimplemented += 1;
+            }
+
+            for (part, problem) in (1..).zip(day.parts()) {
+                if let Some(elapsed) = problem.elapsed() {
+                    summary.total_time += elapsed;
+
+                    if summary.slowest.is_none_or(|(_, _, e)| elapsed > e) {
+                        summary.slowest = Some((day.advent_day(), part, elapsed));
+                    }
+                }
+            }
+        }
+
+        summary
+    }
+
+    pub fn completion(&self) -> f64 {
+        self.stars as f64 / TOTAL_STARS as f64
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::days::init_days;
+
+    #[test]
+    fn counts_stars_before_solving() {
+        let summary = Summary::new(&init_days());
+
+        assert_eq!(summary.stars, 12);
+        assert_eq!(summary.total_time, Duration::ZERO);
+        assert!(summary.slowest.is_none());
+        assert_eq!(summary.errored, 0);
+        assert_eq!(summary.unimplemented, 1);
+    }
+}
@@ -1,12 +1,16 @@
-use crate::{app::App, ui};
+use crate::{app::App, config::Config, days::catch_panic, ui};
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    event::{
+        self, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+        Event, KeyEventKind,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use std::{
     error::Error,
     io,
+    panic::{self, AssertUnwindSafe},
     time::{Duration, Instant},
 };
 use tui::{
@@ -14,29 +18,47 @@ use tui::{
     Terminal,
 };
 
-pub fn run(tick_rate: Duration, enhanced_graphics: bool) -> Result<(), Box<dyn Error>> {
+pub fn run(
+    tick_rate: Duration,
+    enhanced_graphics: bool,
+    config: Config,
+) -> Result<(), Box<dyn Error>> {
     // setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
+    // Solver panics are caught and shown as failures in the UI, so keep the
+    // default hook from printing them over it.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
     // create app and run it
-    let app = App::new("AoC 2022", enhanced_graphics);
-    let res = run_app(&mut terminal, app, tick_rate);
+    let app = App::new("AoC 2022", enhanced_graphics, config);
+    let res = catch_panic(AssertUnwindSafe(|| run_app(&mut terminal, app, tick_rate)));
 
     // restore terminal
     disable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
-        DisableMouseCapture
+        DisableMouseCapture,
+        DisableBracketedPaste
     )?;
     terminal.show_cursor()?;
+    panic::set_hook(hook);
 
-    if let Err(err) = res {
-        println!("{:?}", err)
+    match res {
+        Ok(Err(err)) => println!("{:?}", err),
+        Err(message) => panic!("{}", message),
+        Ok(Ok(())) => {}
     }
 
     Ok(())
@@ -55,13 +77,11 @@ fn run_app<B: Backend>(
             .checked_sub(last_tick.elapsed())
             .unwrap_or_else(|| Duration::from_secs(0));
         if crossterm::event::poll(timeout)? {
-            if let Event::Key(key) = event::read()? {
-                match key.code {
-                    KeyCode::Char(c) => app.on_key(c),
-                    KeyCode::Up => app.on_up(),
-                    KeyCode::Down => app.on_down(),
-                    _ => {}
-                }
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => app.on_key(key),
+                Event::Mouse(mouse) => app.on_mouse(mouse),
+                Event::Paste(text) => app.on_paste(text),
+                _ => {}
             }
         }
         if last_tick.elapsed() >= tick_rate {
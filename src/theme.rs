@@ -0,0 +1,110 @@
+use serde::Deserialize;
+use tui::{style::Color, widgets::BorderType};
+
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemeName {
+    #[default]
+    Dark,
+    Light,
+    HighContrast,
+}
+
+pub struct Theme {
+    pub text: Color,
+    pub background: Color,
+    pub header: Color,
+    pub border: Color,
+    pub highlight: Color,
+    pub stars: Color,
+    pub error: Color,
+}
+
+impl Theme {
+    pub fn new(name: ThemeName) -> Self {
+        match name {
+            ThemeName::Dark => Theme {
+                text: Color::White,
+                background: Color::Reset,
+                header: Color::Gray,
+                border: Color::DarkGray,
+                highlight: Color::Green,
+                stars: Color::Yellow,
+                error: Color::Red,
+            },
+            ThemeName::Light => Theme {
+                text: Color::Black,
+                background: Color::White,
+                header: Color::DarkGray,
+                border: Color::Gray,
+                highlight: Color::Blue,
+                stars: Color::Magenta,
+                error: Color::Red,
+            },
+            ThemeName::HighContrast => Theme {
+                text: Color::White,
+                background: Color::Black,
+                header: Color::LightYellow,
+                border: Color::White,
+                highlight: Color::LightCyan,
+                stars: Color::LightYellow,
+                error: Color::LightRed,
+            },
+        }
+    }
+}
+
+/// Glyphs that depend on whether the terminal can show Unicode.
+pub struct Symbols {
+    pub star: &'static str,
+    pub highlight: &'static str,
+    pub ellipsis: &'static str,
+    pub ascending: &'static str,
+    pub descending: &'static str,
+    /// Example check results: passed, failed and not checked.
+    pub examples: [&'static str; 3],
+    /// Separator between tabs.
+    pub divider: &'static str,
+    /// Horizontal line, such as the budget line on the benchmarks chart.
+    pub line: &'static str,
+    /// Prefix for microseconds.
+    pub micro: &'static str,
+    /// Whether gauges may use Unicode block characters.
+    pub gauge_unicode: bool,
+    /// `None` draws borders in pure ASCII.
+    pub border_type: Option<BorderType>,
+}
+
+impl Symbols {
+    pub fn new(enhanced_graphics: bool) -> Self {
+        if enhanced_graphics {
+            Symbols {
+                star: "★",
+                highlight: "» ",
+                ellipsis: "…",
+                ascending: "▲",
+                descending: "▼",
+                examples: ["✓", "✗", "·"],
+                divider: "│",
+                line: "─",
+                micro: "µ",
+                gauge_unicode: true,
+                border_type: Some(BorderType::Rounded),
+            }
+        } else {
+            Symbols {
+                star: "*",
+                highlight: "> ",
+                ellipsis: "~",
+                ascending: "^",
+                descending: "v",
+                examples: ["+", "x", "."],
+                divider: "|",
+                line: "-",
+                micro: "u",
+                gauge_unicode: false,
+                border_type: None,
+            }
+        }
+    }
+}
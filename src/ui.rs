@@ -1,58 +1,316 @@
-use crate::app::App;
-use crate::days::Day;
+mod benchmarks;
+mod days;
+mod editor;
+mod logs;
+mod visualizations;
 
+use crate::app::{App, Screen};
+use crate::keymap::Action;
+use crate::summary::{Summary, TOTAL_STARS};
+use crate::theme::{Symbols, Theme};
+
+use std::time::Duration;
 use tui::{
     backend::Backend,
-    layout::{Constraint, Direction, Layout},
-    style::{Color, Modifier, Style},
-    widgets::{Block, Borders, Row, Table},
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Clear, Gauge, Paragraph, Row, Table, Tabs, Widget},
     Frame,
 };
 
-fn header_row<'a>() -> Row<'a> {
-    return Row::new(vec!["Day", "Part 1", "Part 2", "Stars"])
-        .style(Style::default().add_modifier(Modifier::BOLD));
+/// A border drawn with `+`, `-` and `|` for terminals without Unicode box
+/// drawing characters.
+struct AsciiBorder<'a> {
+    title: &'a str,
+    style: Style,
+}
+
+impl<'a> Widget for AsciiBorder<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width < 2 || area.height < 2 {
+            return;
+        }
+
+        let (left, right) = (area.left(), area.right() - 1);
+        let (top, bottom) = (area.top(), area.bottom() - 1);
+
+        for x in left..=right {
+            buf.get_mut(x, top).set_symbol("-").set_style(self.style);
+            buf.get_mut(x, bottom).set_symbol("-").set_style(self.style);
+        }
+        for y in top..=bottom {
+            let symbol = if y == top || y == bottom { "+" } else { "|" };
+            buf.get_mut(left, y)
+                .set_symbol(symbol)
+                .set_style(self.style);
+            buf.get_mut(right, y)
+                .set_symbol(symbol)
+                .set_style(self.style);
+        }
+
+        buf.set_stringn(
+            left + 1,
+            top,
+            self.title,
+            area.width.saturating_sub(2) as usize,
+            self.style,
+        );
+    }
+}
+
+/// Draws a titled border around `area` and returns the space inside it.
+fn draw_frame<B: Backend>(f: &mut Frame<B>, app: &App, area: Rect, title: &str) -> Rect {
+    let style = Style::default()
+        .fg(app.theme.border)
+        .bg(app.theme.background);
+    let title = Span::styled(title.to_string(), Style::default().fg(app.theme.text));
+
+    match app.symbols.border_type {
+        Some(border_type) => {
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_type(border_type)
+                .border_style(style)
+                .title(title);
+            let inner = block.inner(area);
+            f.render_widget(block, area);
+            inner
+        }
+        None => {
+            let inner = Block::default().borders(Borders::ALL).inner(area);
+            f.render_widget(
+                AsciiBorder {
+                    title: &title.content,
+                    style,
+                },
+                area,
+            );
+            inner
+        }
+    }
 }
 
-fn day_to_row<'a>(day: &mut Day) -> Row<'a> {
-    let solution_1 = day.part_1.solution().unwrap_or_else(|| String::from(""));
-    let solution_2 = day.part_2.solution().unwrap_or_else(|| String::from(""));
+/// A bold header row. `sorted` replaces the title of the column the table
+/// is sorted by.
+fn header_row<'a>(theme: &Theme, titles: Vec<&'a str>, sorted: Option<(usize, String)>) -> Row<'a> {
+    let mut cells: Vec<String> = titles.into_iter().map(String::from).collect();
+    if let Some((column, title)) = sorted {
+        if let Some(cell) = cells.get_mut(column) {
+            *cell = title;
+        }
+    }
 
-    Row::new(vec![day.label(), solution_1, solution_2, day.stars()])
+    Row::new(cells).style(
+        Style::default()
+            .fg(theme.header)
+            .add_modifier(Modifier::BOLD),
+    )
 }
 
-fn table_rows<'a>(app: &mut App) -> Vec<Row<'a>> {
-    let days = &mut app.day_table.items;
+fn format_duration(duration: Duration, symbols: &Symbols) -> String {
+    let micros = duration.as_micros();
 
-    return days.iter_mut().map(day_to_row).collect();
+    if micros < 1_000 {
+        format!("{}{}s", micros, symbols.micro)
+    } else if micros < 1_000_000 {
+        format!("{:.2}ms", micros as f64 / 1_000.0)
+    } else {
+        format!("{:.2}s", duration.as_secs_f64())
+    }
 }
 
-fn draw_table<B: Backend>(f: &mut Frame<B>, app: &mut App) {
+fn draw_summary<B: Backend>(f: &mut Frame<B>, app: &App, area: Rect) {
+    let summary = Summary::new(&app.day_table.items);
+    let style = Style::default().fg(app.theme.text).bg(app.theme.background);
+
     let chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(100)].as_ref())
-        .split(f.size());
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Length(1)].as_ref())
+        .split(area);
+
+    let slowest = summary.slowest.map_or_else(
+        || String::from("-"),
+        |(day, part, elapsed)| {
+            format!(
+                "Day {} Part {} ({})",
+                day,
+                part,
+                format_duration(elapsed, &app.symbols)
+            )
+        },
+    );
+    let text = format!(
+        "Total time: {} | Slowest: {} | Errored: {} | Unimplemented: {}",
+        format_duration(summary.total_time, &app.symbols),
+        slowest,
+        summary.errored,
+        summary.unimplemented
+    );
+    f.render_widget(Paragraph::new(text).style(style), chunks[0]);
+
+    let gauge = Gauge::default()
+        .gauge_style(
+            Style::default()
+                .fg(app.theme.stars)
+                .bg(app.theme.background),
+        )
+        .ratio(summary.completion())
+        .use_unicode(app.symbols.gauge_unicode)
+        .label(format!(
+            "{}/{} {}",
+            summary.stars, TOTAL_STARS, app.symbols.star
+        ));
+    f.render_widget(gauge, chunks[1]);
+}
+
+fn draw_status_bar<B: Backend>(f: &mut Frame<B>, app: &App, area: Rect) {
+    let text = if app.filter.editing {
+        format!("/{}", app.filter.query)
+    } else {
+        app.status
+            .as_ref()
+            .map_or_else(String::new, |status| status.text.clone())
+    };
+
+    let style = Style::default().fg(app.theme.text).bg(app.theme.background);
+    f.render_widget(Paragraph::new(text).style(style), area);
+}
+
+const HINT_ACTIONS: [(Action, &str); 6] = [
+    (Action::Help, "help"),
+    (Action::Solve, "solve"),
+    (Action::SolveAll, "solve all"),
+    (Action::CopyPart1, "copy"),
+    (Action::OpenPuzzle, "open"),
+    (Action::Quit, "quit"),
+];
 
-    let app_wrapper = Block::default().borders(Borders::ALL).title(app.title);
+fn draw_hint_bar<B: Backend>(f: &mut Frame<B>, app: &App, area: Rect) {
+    let hints: Vec<String> = HINT_ACTIONS
+        .iter()
+        .filter_map(|(action, hint)| {
+            let key = app.keymap.keys_for(*action).into_iter().next()?;
+            Some(format!("{} {}", key, hint))
+        })
+        .collect();
 
-    let rows = table_rows(app);
+    let style = Style::default()
+        .fg(app.theme.header)
+        .bg(app.theme.background);
+    f.render_widget(Paragraph::new(hints.join(" | ")).style(style), area);
+}
+
+fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+fn draw_help<B: Backend>(f: &mut Frame<B>, app: &mut App) {
+    // Borders and the header row add three lines.
+    let area = centered_rect(64, Action::ALL.len() as u16 + 3, f.size());
+    let height = area.height.saturating_sub(3);
+    app.set_help_height(height);
 
+    let first = app.help_scroll as usize;
+    let shown = Action::ALL.iter().skip(first).take(height as usize);
+    let rows: Vec<Row> = shown
+        .map(|action| {
+            Row::new(vec![
+                app.keymap.keys_for(*action).join(", "),
+                action.description().to_string(),
+            ])
+        })
+        .collect();
+
+    let title = if rows.len() < Action::ALL.len() {
+        format!(
+            "Help {}-{} of {}",
+            first + 1,
+            first + rows.len(),
+            Action::ALL.len()
+        )
+    } else {
+        String::from("Help")
+    };
+
+    f.render_widget(Clear, area);
+    let inner = draw_frame(f, app, area, &title);
+
+    let widths = [
+        Constraint::Length(20),
+        Constraint::Length(inner.width.saturating_sub(21)),
+    ];
     let table = Table::new(rows)
-        .header(header_row())
-        .widths(&[
-            Constraint::Percentage(50),
-            Constraint::Percentage(20),
-            Constraint::Percentage(20),
-            Constraint::Percentage(10),
-        ])
-        .style(Style::default().fg(Color::White))
-        .column_spacing(1)
-        .highlight_style(Style::default().fg(Color::Green))
-        .block(app_wrapper);
+        .header(header_row(&app.theme, vec!["Keys", "Action"], None))
+        .widths(&widths)
+        .style(Style::default().fg(app.theme.text).bg(app.theme.background))
+        .column_spacing(1);
+
+    f.render_widget(table, inner);
+}
+
+fn draw_tabs<B: Backend>(f: &mut Frame<B>, app: &App, area: Rect) {
+    let titles: Vec<Spans> = Screen::ALL
+        .iter()
+        .enumerate()
+        .map(|(i, screen)| Spans::from(format!("{} {}", i + 1, screen.title())))
+        .collect();
+
+    let tabs = Tabs::new(titles)
+        .select(app.screen.index())
+        .style(
+            Style::default()
+                .fg(app.theme.header)
+                .bg(app.theme.background),
+        )
+        .highlight_style(
+            Style::default()
+                .fg(app.theme.highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .divider(app.symbols.divider);
 
-    f.render_stateful_widget(table, chunks[0], &mut app.day_table.state);
+    f.render_widget(tabs, area);
 }
 
 pub fn draw<B: Backend>(f: &mut Frame<B>, app: &mut App) {
-    draw_table(f, app);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(1),
+                Constraint::Min(0),
+                Constraint::Length(2),
+                Constraint::Length(1),
+                Constraint::Length(1),
+            ]
+            .as_ref(),
+        )
+        .split(f.size());
+
+    draw_tabs(f, app, chunks[0]);
+
+    match app.screen {
+        Screen::Days => days::draw(f, app, chunks[1]),
+        Screen::Benchmarks => benchmarks::draw(f, app, chunks[1]),
+        Screen::Logs => logs::draw(f, app, chunks[1]),
+        Screen::Visualizations => visualizations::draw(f, app, chunks[1]),
+    }
+
+    draw_summary(f, app, chunks[2]);
+    draw_status_bar(f, app, chunks[3]);
+    draw_hint_bar(f, app, chunks[4]);
+
+    if app.show_help {
+        draw_help(f, app);
+    }
 }
@@ -0,0 +1,150 @@
+use super::{draw_frame, format_duration};
+use crate::app::App;
+
+use std::time::Duration;
+use tui::{
+    backend::Backend,
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    widgets::{BarChart, Paragraph, Widget, Wrap},
+    Frame,
+};
+
+/// Bar heights are `log10` of the runtime in microseconds, scaled up so that
+/// `BarChart`'s integer values keep some precision. One is added so that
+/// parts under a microsecond still get a visible bar.
+const LOG_SCALE: f64 = 100.0;
+
+fn log_value(duration: Duration) -> u64 {
+    let micros = (duration.as_secs_f64() * 1_000_000.0).max(1.0);
+    ((micros.log10() + 1.0) * LOG_SCALE) as u64
+}
+
+/// A horizontal line at `value` across the empty parts of a bar chart
+/// rendered into the same area, scaled the same way `BarChart` scales bars.
+struct BudgetLine {
+    value: u64,
+    max: u64,
+    symbol: &'static str,
+    style: Style,
+}
+
+impl Widget for BudgetLine {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.height < 2 {
+            return;
+        }
+
+        let bar_rows = u64::from(area.height - 1);
+        let level = (self.value * bar_rows / self.max.max(1)).min(bar_rows - 1) as u16;
+        let y = area.bottom() - 2 - level;
+
+        for x in area.left()..area.right() {
+            let cell = buf.get_mut(x, y);
+            if cell.symbol == " " {
+                cell.set_symbol(self.symbol).set_style(self.style);
+            }
+        }
+    }
+}
+
+pub fn draw<B: Backend>(f: &mut Frame<B>, app: &App, area: Rect) {
+    let inner = draw_frame(f, app, area, "Runtime per part (log scale)");
+    let style = Style::default().fg(app.theme.text).bg(app.theme.background);
+
+    let timings: Vec<(String, Duration)> = app
+        .day_table
+        .items
+        .iter()
+        .flat_map(|day| {
+            (1..).zip(day.parts()).filter_map(move |(part, problem)| {
+                let elapsed = problem.elapsed()?;
+                Some((format!("{}.{}", day.advent_day(), part), elapsed))
+            })
+        })
+        .collect();
+
+    if timings.is_empty() {
+        let text = Paragraph::new("Solve some days to compare their runtimes.").style(style);
+        f.render_widget(text, inner);
+        return;
+    }
+
+    let budget = Duration::from_millis(app.config.runtime_budget_ms);
+    let parts = app
+        .day_table
+        .items
+        .iter()
+        .flat_map(|day| day.parts())
+        .filter(|problem| problem.is_implemented())
+        .count()
+        .max(1);
+    let part_budget = budget / parts as u32;
+    let total: Duration = timings.iter().map(|(_, elapsed)| *elapsed).sum();
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(2)].as_ref())
+        .split(inner);
+
+    let max = timings
+        .iter()
+        .map(|(_, elapsed)| log_value(*elapsed))
+        .chain([log_value(budget)])
+        .max()
+        .unwrap_or(1);
+    let data: Vec<(&str, u64)> = timings
+        .iter()
+        .map(|(label, elapsed)| (label.as_str(), log_value(*elapsed)))
+        .collect();
+
+    let bar_width = (chunks[0].width / data.len() as u16)
+        .saturating_sub(1)
+        .clamp(3, 8);
+    let bar_style = Style::default()
+        .fg(app.theme.highlight)
+        .bg(app.theme.background);
+    let chart = BarChart::default()
+        .data(&data)
+        .max(max)
+        .bar_width(bar_width)
+        .bar_gap(1)
+        .bar_style(bar_style)
+        // The scaled log values mean nothing to a reader, so hide them.
+        .value_style(
+            Style::default()
+                .fg(app.theme.highlight)
+                .bg(app.theme.highlight),
+        )
+        .label_style(style)
+        .style(style);
+
+    f.render_widget(chart, chunks[0]);
+    let budget_line = BudgetLine {
+        value: log_value(part_budget),
+        max,
+        symbol: app.symbols.line,
+        style: Style::default().fg(app.theme.error),
+    };
+    f.render_widget(budget_line, chunks[0]);
+
+    let legend: Vec<String> = timings
+        .iter()
+        .map(|(label, elapsed)| format!("{} {}", label, format_duration(*elapsed, &app.symbols)))
+        .collect();
+    let caption = format!(
+        "Total {} of {} budget ({:.0}%), line at {} per part | {}",
+        format_duration(total, &app.symbols),
+        format_duration(budget, &app.symbols),
+        total.as_secs_f64() / budget.as_secs_f64() * 100.0,
+        format_duration(part_budget, &app.symbols),
+        legend.join("  ")
+    );
+    f.render_widget(
+        Paragraph::new(caption)
+            .style(style)
+            .wrap(Wrap { trim: true }),
+        chunks[1],
+    );
+}
@@ -0,0 +1,422 @@
+use super::{draw_frame, editor, format_duration, header_row};
+use crate::app::App;
+use crate::days::{Day, Examples, Problem, Status};
+use crate::theme::Symbols;
+
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    widgets::{Cell, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+/// Below this many columns inside the border, the table drops the day
+/// titles and the stars header.
+const COMPACT_WIDTH: u16 = 60;
+const MAX_ANSWER_WIDTH: usize = 24;
+const COMPACT_ANSWER_WIDTH: usize = 12;
+/// One example result for each part.
+const EXAMPLES_WIDTH: usize = 2;
+
+/// Shows `width` characters of `text` starting at `offset`, marking either
+/// side that is cut off with `ellipsis`.
+fn clip(text: &str, offset: usize, width: usize, ellipsis: &str) -> String {
+    let len = text.chars().count();
+    let offset = offset.min(len.saturating_sub(width));
+    let ellipsis_len = ellipsis.chars().count();
+
+    if len <= width {
+        return text.to_string();
+    }
+    if width <= ellipsis_len * 2 {
+        return text.chars().skip(offset).take(width).collect();
+    }
+
+    let cut_start = offset > 0;
+    let cut_end = offset + width < len;
+    let start = offset + if cut_start { ellipsis_len } else { 0 };
+    let end = offset + width - if cut_end { ellipsis_len } else { 0 };
+
+    let mut clipped = String::new();
+    if cut_start {
+        clipped.push_str(ellipsis);
+    }
+    clipped.extend(text.chars().skip(start).take(end - start));
+    if cut_end {
+        clipped.push_str(ellipsis);
+    }
+    clipped
+}
+
+pub(super) fn answer_text(status: &Status) -> String {
+    match status {
+        Status::Unsolved => String::new(),
+        Status::Solved(solution) => match solution.answer.lines().count() {
+            0 | 1 => solution.answer.clone(),
+            count => format!(
+                "{} (+{} lines)",
+                solution.answer.lines().next().unwrap_or_default(),
+                count - 1
+            ),
+        },
+        Status::Failed(_) => String::from("error"),
+    }
+}
+
+fn examples_cell<'a>(app: &App, day: &Day) -> Cell<'a> {
+    let [passed, failed, unchecked] = app.symbols.examples;
+    let symbols: String = day
+        .parts()
+        .iter()
+        .map(|problem| match problem.examples() {
+            Examples::Passed(_) => passed,
+            Examples::Failed(_) => failed,
+            Examples::Unchecked => unchecked,
+        })
+        .collect();
+
+    let failing = day
+        .parts()
+        .iter()
+        .any(|problem| matches!(problem.examples(), Examples::Failed(_)));
+    let color = if failing {
+        app.theme.error
+    } else {
+        app.theme.stars
+    };
+    Cell::from(symbols).style(Style::default().fg(color))
+}
+
+fn day_label(day: &Day, compact: bool) -> String {
+    if compact {
+        format!("Day {}", day.advent_day())
+    } else {
+        day.label()
+    }
+}
+
+struct ColumnWidths {
+    label: usize,
+    part_1: usize,
+    part_2: usize,
+    stars: usize,
+}
+
+/// Fits the columns into `width`: answers get up to `answer_cap` characters,
+/// the label gets what it needs from the rest, and anything left over lets
+/// long answers grow.
+fn column_widths(
+    width: usize,
+    labels: &[usize],
+    answers: [&[usize]; 2],
+    stars: usize,
+    answer_cap: usize,
+) -> ColumnWidths {
+    let natural =
+        |lens: &[usize], header: usize| lens.iter().copied().max().unwrap_or(0).max(header);
+    let label_natural = natural(labels, 3);
+    let answer_natural = [natural(answers[0], 6), natural(answers[1], 6)];
+
+    // Three single-space gaps between the four columns.
+    let available = width.saturating_sub(stars + 3);
+    let mut part_1 = answer_natural[0].min(answer_cap);
+    let mut part_2 = answer_natural[1].min(answer_cap);
+    // On very narrow terminals the answers give way too, keeping a few
+    // columns for the label.
+    let answer_room = available.saturating_sub(label_natural.min(5));
+    if part_1 + part_2 > answer_room {
+        part_1 = part_1.min(answer_room / 2);
+        part_2 = part_2.min(answer_room - part_1);
+    }
+    let label = label_natural.min(available.saturating_sub(part_1 + part_2).max(5));
+
+    let mut leftover = available.saturating_sub(label + part_1 + part_2);
+    let grow_1 = (answer_natural[0] - part_1).min(leftover / 2);
+    part_1 += grow_1;
+    leftover -= grow_1;
+    part_2 += (answer_natural[1] - part_2).min(leftover);
+
+    ColumnWidths {
+        label,
+        part_1,
+        part_2,
+        stars,
+    }
+}
+
+fn draw_table<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
+    let title = if app.filter.is_active() {
+        format!(
+            "{} [{}] {}/{}",
+            app.title,
+            app.filter.describe(),
+            app.day_table.visible_len(),
+            app.day_table.items.len()
+        )
+    } else {
+        app.title.to_string()
+    };
+    let inner = draw_frame(f, app, area, &title);
+
+    let compact = inner.width < COMPACT_WIDTH;
+    let days: Vec<&Day> = app.day_table.visible_items().collect();
+    let labels: Vec<String> = days.iter().map(|day| day_label(day, compact)).collect();
+    let answers: Vec<[String; 2]> = days
+        .iter()
+        .map(|day| {
+            [
+                answer_text(day.part_1.status()),
+                answer_text(day.part_2.status()),
+            ]
+        })
+        .collect();
+
+    let longest = answers
+        .iter()
+        .flatten()
+        .map(|a| a.chars().count())
+        .max()
+        .unwrap_or(0);
+    app.answer_scroll = app.answer_scroll.min(longest.saturating_sub(1));
+
+    let (sorted_column, sorted_title) = match (app.sort.key.column(), compact) {
+        ((3, _), true) => (3, app.symbols.star),
+        (column, _) => column,
+    };
+    let arrow = if app.sort.descending {
+        app.symbols.descending
+    } else {
+        app.symbols.ascending
+    };
+    let sorted_title = format!("{} {}", sorted_title, arrow);
+    let sorted_len = sorted_title.chars().count();
+
+    // The sorted column's header counts towards its width like a cell does.
+    let header_len = |column: usize| {
+        if column == sorted_column {
+            sorted_len
+        } else {
+            0
+        }
+    };
+    let answer_lens = |part: usize| -> Vec<usize> {
+        answers
+            .iter()
+            .map(|a| a[part].chars().count().saturating_sub(app.answer_scroll))
+            .chain([header_len(part + 1)])
+            .collect()
+    };
+    let label_lens: Vec<usize> = labels
+        .iter()
+        .map(|l| l.chars().count())
+        .chain([header_len(0)])
+        .collect();
+    let highlight_width = app.symbols.highlight.chars().count();
+    let widths = column_widths(
+        (inner.width as usize).saturating_sub(highlight_width + EXAMPLES_WIDTH + 1),
+        &label_lens,
+        [&answer_lens(0), &answer_lens(1)],
+        header_len(3).max(if compact { 2 } else { 5 }),
+        if compact {
+            COMPACT_ANSWER_WIDTH
+        } else {
+            MAX_ANSWER_WIDTH
+        },
+    );
+
+    let answer_style = |problem: &Problem| match problem.status() {
+        Status::Failed(_) => Style::default().fg(app.theme.error),
+        _ => Style::default(),
+    };
+    let ellipsis = app.symbols.ellipsis;
+    let rows: Vec<Row> = days
+        .iter()
+        .zip(labels.iter().zip(answers.iter()))
+        .map(|(day, (label, answer))| {
+            let stars = Cell::from(app.symbols.star.repeat(day.star_count()))
+                .style(Style::default().fg(app.theme.stars));
+
+            Row::new(vec![
+                Cell::from(clip(label, 0, widths.label, ellipsis)),
+                Cell::from(clip(&answer[0], app.answer_scroll, widths.part_1, ellipsis))
+                    .style(answer_style(&day.part_1)),
+                Cell::from(clip(&answer[1], app.answer_scroll, widths.part_2, ellipsis))
+                    .style(answer_style(&day.part_2)),
+                stars,
+                examples_cell(app, day),
+            ])
+        })
+        .collect();
+
+    // When the selected day's answers don't fit, show them in full on a line
+    // under the table.
+    let selected_full = app
+        .day_table
+        .state
+        .selected()
+        .and_then(|i| answers.get(i))
+        .filter(|a| a[0].chars().count() > widths.part_1 || a[1].chars().count() > widths.part_2)
+        .map(|a| format!("Part 1: {} | Part 2: {}", a[0], a[1]));
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Min(0),
+                Constraint::Length(if selected_full.is_some() { 1 } else { 0 }),
+            ]
+            .as_ref(),
+        )
+        .split(inner);
+
+    let constraints = [
+        Constraint::Length(widths.label as u16),
+        Constraint::Length(widths.part_1 as u16),
+        Constraint::Length(widths.part_2 as u16),
+        Constraint::Length(widths.stars as u16),
+        Constraint::Length(EXAMPLES_WIDTH as u16),
+    ];
+    let headers = if compact {
+        vec!["Day", "Part 1", "Part 2", app.symbols.star, "Ex"]
+    } else {
+        vec!["Day", "Part 1", "Part 2", "Stars", "Ex"]
+    };
+    let table = Table::new(rows)
+        .header(header_row(
+            &app.theme,
+            headers,
+            Some((sorted_column, sorted_title)),
+        ))
+        .widths(&constraints)
+        .style(Style::default().fg(app.theme.text).bg(app.theme.background))
+        .column_spacing(1)
+        .highlight_symbol(app.symbols.highlight)
+        .highlight_style(
+            Style::default()
+                .fg(app.theme.highlight)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    // Leave out the header row.
+    app.table_rows = Rect {
+        y: chunks[0].y + 1,
+        height: chunks[0].height.saturating_sub(1),
+        ..chunks[0]
+    };
+    app.day_table.update_offset(app.table_rows.height as usize);
+
+    f.render_stateful_widget(table, chunks[0], &mut app.day_table.state);
+
+    if let Some(full) = selected_full {
+        let style = Style::default()
+            .fg(app.theme.highlight)
+            .bg(app.theme.background);
+        f.render_widget(Paragraph::new(full).style(style), chunks[1]);
+    }
+}
+
+fn detail_lines(day: &Day, symbols: &Symbols) -> Vec<String> {
+    let mut lines = vec![day.label(), day.url(), String::new()];
+
+    for (part, problem) in [(1, &day.part_1), (2, &day.part_2)] {
+        lines.push(format!("Part {} ({})", part, problem.path()));
+        match problem.status() {
+            Status::Unsolved => lines.push(String::from("-")),
+            Status::Solved(solution) => match solution.lines().as_slice() {
+                [answer] => lines.push(format!(
+                    "{} (in {})",
+                    answer,
+                    format_duration(solution.elapsed, symbols)
+                )),
+                answer => {
+                    lines.push(format!(
+                        "Solved in {}",
+                        format_duration(solution.elapsed, symbols)
+                    ));
+                    lines.extend(answer.iter().map(|line| line.to_string()));
+                }
+            },
+            Status::Failed(error) => lines.push(format!("Error: {}", error)),
+        }
+        match problem.examples() {
+            Examples::Unchecked => {}
+            Examples::Passed(count) => lines.push(format!("Examples passed: {}", count)),
+            Examples::Failed(error) => lines.push(format!("Example failed: {}", error)),
+        }
+        lines.push(String::new());
+    }
+
+    lines
+}
+
+fn draw_detail<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
+    let lines = app.day_table.current_item().map_or_else(
+        || vec![String::from("No day selected")],
+        |day| detail_lines(day, &app.symbols),
+    );
+
+    let max_scroll = (lines.len() as u16).saturating_sub(1);
+    app.detail_scroll = app.detail_scroll.min(max_scroll);
+    app.detail_area = Some(area);
+
+    let inner = draw_frame(f, app, area, "Detail");
+    let detail = Paragraph::new(lines.join("\n"))
+        .style(Style::default().fg(app.theme.text).bg(app.theme.background))
+        .wrap(Wrap { trim: false })
+        .scroll((app.detail_scroll, 0));
+
+    f.render_widget(detail, inner);
+}
+
+pub fn draw<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
+    if app.editor.is_some() {
+        let main = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+            .split(area);
+
+        app.detail_area = None;
+        draw_table(f, app, main[0]);
+        editor::draw(f, app, main[1]);
+    } else if app.show_detail {
+        let main = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)].as_ref())
+            .split(area);
+
+        draw_table(f, app, main[0]);
+        draw_detail(f, app, main[1]);
+    } else {
+        app.detail_area = None;
+        draw_table(f, app, area);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn clip_marks_cut_off_sides() {
+        assert_eq!(clip("SVFDLGLWV", 0, 9, "~"), "SVFDLGLWV");
+        assert_eq!(clip("SVFDLGLWV", 0, 6, "~"), "SVFDL~");
+        assert_eq!(clip("SVFDLGLWV", 2, 6, "~"), "~DLGL~");
+        assert_eq!(clip("SVFDLGLWV", 8, 6, "~"), "~LGLWV");
+    }
+
+    #[test]
+    fn column_widths_fit_the_content() {
+        let widths = column_widths(80, &[24, 28], [&[5, 9], &[6, 9]], 5, MAX_ANSWER_WIDTH);
+        assert_eq!(widths.label, 28);
+        assert!(widths.part_1 >= 9 && widths.part_2 >= 9);
+        assert!(widths.label + widths.part_1 + widths.part_2 + widths.stars + 3 <= 80);
+    }
+
+    #[test]
+    fn column_widths_truncate_long_answers_when_narrow() {
+        let widths = column_widths(40, &[28], [&[40], &[40]], 5, MAX_ANSWER_WIDTH);
+        assert!(widths.label + widths.part_1 + widths.part_2 + widths.stars + 3 <= 40);
+        assert!(widths.part_1 < 40);
+    }
+}
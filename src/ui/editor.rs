@@ -0,0 +1,85 @@
+use super::{days::answer_text, draw_frame, header_row};
+use crate::app::App;
+use crate::days::Status;
+
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    widgets::{Paragraph, Row, Table},
+    Frame,
+};
+
+pub fn draw<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
+    let editor = match app.editor.as_ref() {
+        Some(editor) => editor,
+        None => return,
+    };
+    let day = &app.day_table.items[editor.item];
+
+    let title = format!(
+        "Input for Day {} (ctrl-r run, ctrl-s save)",
+        day.advent_day()
+    );
+    let inner = draw_frame(f, app, area, &title);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(3)].as_ref())
+        .split(inner);
+
+    // Compare the custom results with the ones from the real input.
+    let rows: Vec<Row> = (1..=2)
+        .zip(editor.results.iter())
+        .map(|(part, custom)| {
+            let custom = match custom {
+                Status::Failed(error) => format!("error: {}", error),
+                _ => answer_text(custom),
+            };
+            Row::new(vec![
+                format!("Part {}", part),
+                custom,
+                answer_text(day.part(part).status()),
+            ])
+        })
+        .collect();
+    let widths = [
+        Constraint::Length(6),
+        Constraint::Percentage(50),
+        Constraint::Percentage(50),
+    ];
+    let results = Table::new(rows)
+        .header(header_row(&app.theme, vec!["", "Custom", "Real"], None))
+        .widths(&widths)
+        .style(Style::default().fg(app.theme.text).bg(app.theme.background))
+        .column_spacing(1);
+    f.render_widget(results, chunks[1]);
+
+    let text_area = chunks[0];
+    let editor = match app.editor.as_mut() {
+        Some(editor) => editor,
+        None => return,
+    };
+    editor.scroll_to_cursor(text_area.width as usize, text_area.height as usize);
+
+    let text: Vec<String> = editor
+        .lines()
+        .iter()
+        .skip(editor.top)
+        .take(text_area.height as usize)
+        .map(|line| {
+            line.chars()
+                .skip(editor.left)
+                .take(text_area.width as usize)
+                .collect()
+        })
+        .collect();
+    let (row, column) = editor.cursor();
+    let cursor = (
+        text_area.x + (column - editor.left) as u16,
+        text_area.y + (row - editor.top) as u16,
+    );
+
+    let style = Style::default().fg(app.theme.text).bg(app.theme.background);
+    f.render_widget(Paragraph::new(text.join("\n")).style(style), text_area);
+    f.set_cursor(cursor.0, cursor.1);
+}
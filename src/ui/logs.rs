@@ -0,0 +1,16 @@
+use super::draw_frame;
+use crate::app::App;
+
+use tui::{backend::Backend, layout::Rect, style::Style, widgets::Paragraph, Frame};
+
+pub fn draw<B: Backend>(f: &mut Frame<B>, app: &mut App, area: Rect) {
+    let inner = draw_frame(f, app, area, "Logs");
+    app.set_log_height(inner.height);
+    let scroll = app.log_offset();
+
+    let logs = Paragraph::new(app.logs.join("\n"))
+        .style(Style::default().fg(app.theme.text).bg(app.theme.background))
+        .scroll((scroll, 0));
+
+    f.render_widget(logs, inner);
+}
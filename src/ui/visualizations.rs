@@ -0,0 +1,37 @@
+use super::draw_frame;
+use crate::app::App;
+
+use tui::{backend::Backend, layout::Rect, style::Style, widgets::Paragraph, Frame};
+
+pub fn draw<B: Backend>(f: &mut Frame<B>, app: &App, area: Rect) {
+    let style = Style::default().fg(app.theme.text).bg(app.theme.background);
+
+    let day = match app.day_table.current_item() {
+        Some(day) => day,
+        None => {
+            let inner = draw_frame(f, app, area, "Visualization");
+            f.render_widget(Paragraph::new("No day selected.").style(style), inner);
+            return;
+        }
+    };
+
+    let visualization = app
+        .visualization
+        .as_ref()
+        .filter(|v| v.advent_day == day.advent_day() && !v.frames.is_empty());
+
+    let (title, text) = match visualization {
+        Some(v) => (
+            format!("{} - frame {}/{}", day.label(), v.frame + 1, v.frames.len()),
+            v.frames[v.frame].clone(),
+        ),
+        None if day.has_visualizer() => (
+            day.label(),
+            format!("Could not read the input at {}.", day.part_1.path()),
+        ),
+        None => (day.label(), String::from("No visualization for this day.")),
+    };
+
+    let inner = draw_frame(f, app, area, &title);
+    f.render_widget(Paragraph::new(text).style(style), inner);
+}
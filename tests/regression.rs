@@ -0,0 +1,60 @@
+//! Runs every registered day on its real input and compares the results with
+//! the answers accepted on the site, recorded in `inputs/day_N/answers.toml`.
+//! Days without an input or a recorded answer are skipped, since inputs are
+//! personal and may not be checked out. Inputs are parsed strictly, so a
+//! truncated copy fails loudly rather than giving a wrong answer.
+
+use aoc2022::days::{init_days, Parsing};
+
+use std::io::ErrorKind;
+
+#[test]
+fn real_inputs_give_the_recorded_answers() {
+    let mut failures = Vec::new();
+
+    for mut day in init_days() {
+        day.set_parsing(Parsing::Strict);
+        let answers = day.answers().expect("answers file should be valid TOML");
+
+        for part in 1..=2 {
+            let problem = day.part(part);
+            let expected = match answers.part(part) {
+                Some(expected) => expected,
+                None => continue,
+            };
+            let input = match problem.read_input() {
+                Ok(input) => input,
+                Err(e) if e.kind() == ErrorKind::NotFound => {
+                    eprintln!("Skipping Day {} Part {}: no input", day.advent_day(), part);
+                    continue;
+                }
+                Err(e) => panic!("Could not read {}: {}", problem.path(), e),
+            };
+
+            match problem.solve_input(&input) {
+                Some(Ok(solution)) if solution.answer == expected => {}
+                Some(Ok(solution)) => failures.push(format!(
+                    "Day {} Part {}: expected {}, got {}",
+                    day.advent_day(),
+                    part,
+                    expected,
+                    solution.answer
+                )),
+                Some(Err(e)) => failures.push(format!(
+                    "Day {} Part {}: expected {}, got error: {}",
+                    day.advent_day(),
+                    part,
+                    expected,
+                    e
+                )),
+                None => failures.push(format!(
+                    "Day {} Part {}: has an answer but no solver",
+                    day.advent_day(),
+                    part
+                )),
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}